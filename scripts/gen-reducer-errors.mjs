@@ -0,0 +1,47 @@
+#!/usr/bin/env node
+// Generates src/spacetime_module_bindings/reducer_error.ts from the error code list
+// in the SpacetimeDB module. Run after `spacetime generate` (see `npm run st:gen`).
+import { readFileSync, writeFileSync } from 'node:fs';
+import { fileURLToPath } from 'node:url';
+import path from 'node:path';
+
+const root = path.resolve(path.dirname(fileURLToPath(import.meta.url)), '..');
+const src = path.join(root, 'spacetime-server/rust/footballcaster2/src/error.rs');
+const out = path.join(root, 'src/spacetime_module_bindings/reducer_error.ts');
+
+const rust = readFileSync(src, 'utf8');
+const entries = [...rust.matchAll(/^\s*(\w+) => "([a-z0-9_]+)",$/gm)].map(([, variant, code]) => ({ variant, code }));
+if (entries.length === 0) {
+  console.error(`No error codes found in ${src}`);
+  process.exit(1);
+}
+
+const lines = [
+  '// THIS FILE IS AUTOMATICALLY GENERATED BY scripts/gen-reducer-errors.mjs FROM',
+  '// spacetime-server/rust/footballcaster2/src/error.rs. EDIT THE RUST SOURCE INSTEAD.',
+  '',
+  '/* eslint-disable */',
+  '/* tslint:disable */',
+  'export const ReducerErrorCode = {',
+  ...entries.map(({ variant, code }) => `  ${variant}: '${code}',`),
+  '} as const;',
+  '',
+  'export type ReducerErrorCode = (typeof ReducerErrorCode)[keyof typeof ReducerErrorCode];',
+  '',
+  'const CODES: ReadonlySet<string> = new Set(Object.values(ReducerErrorCode));',
+  '',
+  '/** Extracts the module error code from a failed reducer call, if it carries one. */',
+  'export function parseReducerError(err: unknown): ReducerErrorCode | null {',
+  '  const message = typeof err === \'string\' ? err : (err as any)?.message ?? String(err ?? \'\');',
+  '  const trimmed = String(message).trim();',
+  '  if (CODES.has(trimmed)) return trimmed as ReducerErrorCode;',
+  '  for (const token of trimmed.match(/[a-z0-9_]+/g) ?? []) {',
+  '    if (CODES.has(token)) return token as ReducerErrorCode;',
+  '  }',
+  '  return null;',
+  '}',
+  '',
+];
+
+writeFileSync(out, lines.join('\n'));
+console.info(`Wrote ${entries.length} reducer error codes to ${path.relative(root, out)}`);
@@ -0,0 +1,253 @@
+//! Competition scheduling and table helpers.
+//!
+//! Pure functions used by the league and cup reducers: the round-robin
+//! fixture list, standings order, the rolling form string and knockout
+//! bracket seeding. Callers own all table writes.
+
+use std::cmp::Ordering;
+
+use crate::Standing;
+
+/// Results kept in a standing's form string, most recent last.
+pub const FORM_LENGTH: usize = 5;
+
+/// Double round-robin by the circle method: every manager meets every other
+/// once at home and once away. Returns rounds of (home, away) pairs; with an
+/// odd number of managers one sits out each round.
+pub fn round_robin(fids: &[i64]) -> Vec<Vec<(i64, i64)>> {
+    let mut teams: Vec<Option<i64>> = fids.iter().copied().map(Some).collect();
+    if teams.len() % 2 == 1 { teams.push(None); }
+    let n = teams.len();
+    let mut rounds: Vec<Vec<(i64, i64)>> = Vec::new();
+    for round in 0..n.saturating_sub(1) {
+        let mut pairs = Vec::new();
+        for i in 0..n / 2 {
+            if let (Some(a), Some(b)) = (teams[i], teams[n - 1 - i]) {
+                // Alternate venues so the fixed first slot is not always at home
+                pairs.push(if (round + i) % 2 == 0 { (a, b) } else { (b, a) });
+            }
+        }
+        rounds.push(pairs);
+        teams[1..].rotate_right(1);
+    }
+    // The second half mirrors the first with venues swapped
+    let return_legs: Vec<Vec<(i64, i64)>> = rounds.iter().map(|r| r.iter().map(|&(h, a)| (a, h)).collect()).collect();
+    rounds.extend(return_legs);
+    rounds
+}
+
+/// Table order: points, goal difference, goals scored, then fid for stability.
+pub fn table_order(a: &Standing, b: &Standing) -> Ordering {
+    let gd = |s: &Standing| s.goals_for - s.goals_against;
+    b.points.cmp(&a.points)
+        .then(gd(b).cmp(&gd(a)))
+        .then(b.goals_for.cmp(&a.goals_for))
+        .then(a.fid.cmp(&b.fid))
+}
+
+/// Appends W, D or L to `form`, keeping the last `FORM_LENGTH` results.
+pub fn push_form(form: &str, goals_for: i64, goals_against: i64) -> String {
+    let mark = match goals_for.cmp(&goals_against) {
+        Ordering::Greater => 'W',
+        Ordering::Less => 'L',
+        Ordering::Equal => 'D',
+    };
+    let mut chars: Vec<char> = form.chars().collect();
+    chars.push(mark);
+    let skip = chars.len().saturating_sub(FORM_LENGTH);
+    chars[skip..].iter().collect()
+}
+
+/// Seed numbers (1-based) in bracket slot order for a power-of-two `size`, so
+/// that 1 meets `size` first and the top two seeds can only meet in the final.
+pub fn bracket_seeds(size: usize) -> Vec<usize> {
+    let mut seeds = vec![1];
+    while seeds.len() < size {
+        let mirror = seeds.len() * 2 + 1;
+        seeds = seeds.iter().flat_map(|&s| [s, mirror - s]).collect();
+    }
+    seeds
+}
+
+/// Knockout seeding after a group stage. `by_place[p]` holds the `(fid, group)`
+/// pairs that finished in place `p + 1`. Group winners keep the top seeds; the
+/// later places are reordered, staying as close to their natural order as
+/// possible, so that no first-round tie under `bracket_seeds` pairs two sides
+/// from the same group. Falls back to the natural order if that cannot be done.
+pub fn cross_group_seeds(by_place: &[Vec<(i64, usize)>]) -> Vec<i64> {
+    let natural: Vec<(i64, usize)> = by_place.concat();
+    let fixed = by_place.first().map_or(0, Vec::len);
+    let size = natural.len().next_power_of_two();
+    let mut seeded = natural[..fixed].to_vec();
+    let pool = &natural[fixed..];
+    let mut used = vec![false; pool.len()];
+    let order = if seed_apart(&mut seeded, pool, &mut used, size, natural.len()) { seeded } else { natural };
+    order.into_iter().map(|(fid, _)| fid).collect()
+}
+
+fn seed_apart(seeded: &mut Vec<(i64, usize)>, pool: &[(i64, usize)], used: &mut [bool], size: usize, total: usize) -> bool {
+    let seed = seeded.len() + 1;
+    if seed > total { return true; }
+    // First-round opponent; a seed past `total` is a bye and one not yet placed is checked later
+    let opponent = size + 1 - seed;
+    let mut tried: Vec<usize> = Vec::new();
+    for (i, &(fid, group)) in pool.iter().enumerate() {
+        if used[i] || tried.contains(&group) { continue; }
+        if opponent < seed && seeded[opponent - 1].1 == group { continue; }
+        tried.push(group);
+        used[i] = true;
+        seeded.push((fid, group));
+        if seed_apart(seeded, pool, used, size, total) { return true; }
+        seeded.pop();
+        used[i] = false;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn row(fid: i64, points: i32, goals_for: i64, goals_against: i64) -> Standing {
+        Standing {
+            id: format!("s:{}", fid),
+            season_id: "s".into(),
+            league_id: "l".into(),
+            fid,
+            played: 0,
+            won: 0,
+            drawn: 0,
+            lost: 0,
+            goals_for,
+            goals_against,
+            points,
+            form: String::new(),
+        }
+    }
+
+    #[test]
+    fn round_robin_pairs_everyone_twice_once_at_each_venue() {
+        for n in 2..=9i64 {
+            let fids: Vec<i64> = (1..=n).collect();
+            let rounds = round_robin(&fids);
+            let per_half = if n % 2 == 0 { n - 1 } else { n } as usize;
+            assert_eq!(rounds.len(), per_half * 2, "n={}", n);
+
+            let mut legs: BTreeMap<(i64, i64), usize> = BTreeMap::new();
+            let mut byes: BTreeMap<i64, usize> = BTreeMap::new();
+            for pairs in &rounds {
+                let playing: Vec<i64> = pairs.iter().flat_map(|&(h, a)| [h, a]).collect();
+                let mut unique = playing.clone();
+                unique.sort_unstable();
+                unique.dedup();
+                assert_eq!(unique.len(), playing.len(), "n={}: a manager plays twice in one round", n);
+                for &(h, a) in pairs { *legs.entry((h, a)).or_default() += 1; }
+                for fid in fids.iter().filter(|f| !playing.contains(f)) { *byes.entry(*fid).or_default() += 1; }
+            }
+            for &a in &fids {
+                for &b in fids.iter().filter(|&&b| b != a) {
+                    assert_eq!(legs.get(&(a, b)), Some(&1), "n={}: {} v {}", n, a, b);
+                }
+            }
+            assert_eq!(legs.len() as i64, n * (n - 1));
+            // With an odd count everyone sits out once per half
+            if n % 2 == 1 {
+                assert!(fids.iter().all(|f| byes.get(f) == Some(&2)), "n={}: {:?}", n, byes);
+            } else {
+                assert!(byes.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn table_order_breaks_ties_by_goal_difference_goals_then_fid() {
+        let mut rows = [
+            row(5, 10, 8, 8),  // level on points with 4, worse goal difference
+            row(4, 10, 9, 6),  // +3
+            row(3, 10, 7, 4),  // +3, fewer goals than 4
+            row(2, 12, 0, 10), // most points
+            row(7, 10, 7, 4),  // identical to 3, higher fid
+        ];
+        rows.sort_by(table_order);
+        let order: Vec<i64> = rows.iter().map(|r| r.fid).collect();
+        assert_eq!(order, vec![2, 4, 3, 7, 5]);
+    }
+
+    #[test]
+    fn push_form_keeps_the_latest_results() {
+        assert_eq!(push_form("", 2, 1), "W");
+        assert_eq!(push_form("WD", 0, 3), "WDL");
+        assert_eq!(push_form("WWDLW", 1, 1), "WDLWD");
+    }
+
+    #[test]
+    fn bracket_seeds_keep_top_seeds_apart() {
+        assert_eq!(bracket_seeds(1), vec![1]);
+        assert_eq!(bracket_seeds(2), vec![1, 2]);
+        assert_eq!(bracket_seeds(4), vec![1, 4, 2, 3]);
+        assert_eq!(bracket_seeds(8), vec![1, 8, 4, 5, 2, 7, 3, 6]);
+        for size in [2, 4, 8, 16, 32, 64] {
+            let seeds = bracket_seeds(size);
+            let mut sorted = seeds.clone();
+            sorted.sort_unstable();
+            assert_eq!(sorted, (1..=size).collect::<Vec<_>>());
+            // First-round opponents always sum to size + 1; 1 and 2 sit in opposite halves
+            assert!(seeds.chunks(2).all(|p| p[0] + p[1] == size + 1));
+            let half = &seeds[..size / 2];
+            assert!(half.contains(&1) && !half.contains(&2));
+        }
+    }
+
+    #[test]
+    fn bracket_byes_go_to_the_top_seeds() {
+        for entrants in 2..=64usize {
+            let size = entrants.next_power_of_two();
+            let byes = size - entrants;
+            for pair in bracket_seeds(size).chunks(2) {
+                let (home, away) = (pair[0], pair[1]);
+                assert!(home <= entrants, "entrants={}: slot without a real home side", entrants);
+                // Seeds past the entrant count are byes, and only seeds 1..=byes get one
+                if away > entrants {
+                    assert!(home <= byes, "entrants={}: seed {} got a bye", entrants, home);
+                } else {
+                    assert!(home > byes, "entrants={}: seed {} should have a bye", entrants, home);
+                }
+            }
+        }
+    }
+    fn first_round_groups(seeds: &[i64], group_of: impl Fn(i64) -> usize) -> Vec<(usize, usize)> {
+        let size = seeds.len().next_power_of_two();
+        bracket_seeds(size).chunks(2)
+            .filter(|p| p[1] <= seeds.len())
+            .map(|p| (group_of(seeds[p[0] - 1]), group_of(seeds[p[1] - 1])))
+            .collect()
+    }
+
+    #[test]
+    fn cross_group_seeds_split_three_groups() {
+        // Winners 1..=3 and runners-up 4..=6 of groups 0..=2; natural order would put 3 v 6
+        let by_place = vec![vec![(1, 0), (2, 1), (3, 2)], vec![(4, 0), (5, 1), (6, 2)]];
+        let seeds = cross_group_seeds(&by_place);
+        assert_eq!(seeds, vec![1, 2, 3, 4, 6, 5]);
+        let ties = first_round_groups(&seeds, |fid| ((fid - 1) % 3) as usize);
+        assert!(ties.iter().all(|(a, b)| a != b), "{:?}", ties);
+    }
+
+    #[test]
+    fn cross_group_seeds_never_pair_group_mates_in_round_one() {
+        for groups in 2..=16usize {
+            let by_place: Vec<Vec<(i64, usize)>> = (0..2)
+                .map(|place| (0..groups).map(|g| ((place * groups + g) as i64, g)).collect())
+                .collect();
+            let seeds = cross_group_seeds(&by_place);
+            let mut sorted = seeds.clone();
+            sorted.sort_unstable();
+            assert_eq!(sorted, (0..(2 * groups) as i64).collect::<Vec<_>>(), "groups={}", groups);
+            // Group winners keep the top seeds in order
+            assert_eq!(seeds[..groups], (0..groups as i64).collect::<Vec<_>>()[..], "groups={}", groups);
+            let ties = first_round_groups(&seeds, |fid| fid as usize % groups);
+            assert!(ties.iter().all(|(a, b)| a != b), "groups={}: {:?}", groups, ties);
+        }
+    }
+}
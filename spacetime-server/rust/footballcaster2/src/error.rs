@@ -0,0 +1,118 @@
+//! Stable error codes returned by reducers.
+//!
+//! The code strings are part of the client contract: `npm run st:gen` turns the
+//! list below into `src/spacetime_module_bindings/reducer_error.ts`. Add new codes
+//! rather than renaming existing ones.
+
+use std::fmt;
+
+macro_rules! reducer_errors {
+    ($($variant:ident => $code:literal,)*) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum ReducerError {
+            $($variant,)*
+        }
+
+        impl ReducerError {
+            pub const fn code(self) -> &'static str {
+                match self {
+                    $(ReducerError::$variant => $code,)*
+                }
+            }
+        }
+    };
+}
+
+reducer_errors! {
+    // Identity & authorization
+    IdentityNotLinked => "identity_not_linked",
+    FidMismatch => "fid_mismatch",
+    Forbidden => "forbidden",
+    InvalidRole => "invalid_role",
+    LastAdmin => "last_admin",
+    SchedulerOnly => "scheduler_only",
+    // Inventory
+    ItemNotFound => "item_not_found",
+    NotOwner => "not_owner",
+    InHold => "in_hold",
+    ItemLocked => "item_locked",
+    // Starter pack
+    InvalidPayload => "invalid_payload",
+    StarterAlreadyClaimed => "starter_already_claimed",
+    // Listings
+    ListingNotFound => "listing_not_found",
+    ListingClosed => "listing_closed",
+    PurchasePending => "purchase_pending",
+    ReservationUsed => "reservation_used",
+    PriceBelowMinimum => "price_below_minimum",
+    InvalidMarketLimits => "invalid_market_limits",
+    // Auctions
+    AuctionNotFound => "auction_not_found",
+    AuctionClosed => "auction_closed",
+    AuctionEnded => "auction_ended",
+    AuctionNotEnded => "auction_not_ended",
+    NotWinner => "not_winner",
+    BelowIncrement => "below_increment",
+    InvalidBuyNow => "invalid_buy_now",
+    InvalidAntiSnipe => "invalid_anti_snipe",
+    AuctionTooShort => "auction_too_short",
+    AuctionTooLong => "auction_too_long",
+    InvalidReserve => "invalid_reserve",
+    BuyNowBelowReserve => "buy_now_below_reserve",
+    // Amounts & balances
+    InvalidAmount => "invalid_amount",
+    AmountOverflow => "amount_overflow",
+    InsufficientFunds => "insufficient_funds",
+    EscrowUnderflow => "escrow_underflow",
+    TxAlreadyUsed => "tx_already_used",
+    // PvP
+    SameFid => "same_fid",
+    DuplicatePending => "duplicate_pending",
+    MatchNotFound => "match_not_found",
+    InvalidState => "invalid_state",
+    NotChallenged => "not_challenged",
+    NotChallenger => "not_challenger",
+    NotParticipant => "not_participant",
+    InvalidJson => "invalid_json",
+    MissingHome => "missing_home",
+    MissingAway => "missing_away",
+    NegativeScore => "negative_score",
+    ScoreOutOfRange => "score_out_of_range",
+    AlreadyReported => "already_reported",
+    DisputeNotFound => "dispute_not_found",
+    AlreadyQueued => "already_queued",
+    NotQueued => "not_queued",
+    NpcMatch => "npc_match",
+    // NPCs
+    InsufficientNpcPool => "insufficient_npc_pool",
+    // Player state
+    NotAPlayer => "not_a_player",
+    PlayerStateExists => "player_state_exists",
+    InvalidResult => "invalid_result",
+    OutOfRange => "out_of_range",
+    // Lineups
+    InvalidFormation => "invalid_formation",
+    InvalidSlot => "invalid_slot",
+    PositionMismatch => "position_mismatch",
+    PlayerInjured => "player_injured",
+    NotInLineup => "not_in_lineup",
+    // Leagues
+    LeagueNotFound => "league_not_found",
+    LeagueExists => "league_exists",
+    AlreadyInLeague => "already_in_league",
+    SeasonActive => "season_active",
+    PromotionMismatch => "promotion_mismatch",
+    // Cups
+    CupNotFound => "cup_not_found",
+    CupClosed => "cup_closed",
+    CupFull => "cup_full",
+    AlreadyEntered => "already_entered",
+    NotEnoughEntrants => "not_enough_entrants",
+    InvalidPrizeSplit => "invalid_prize_split",
+}
+
+impl fmt::Display for ReducerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
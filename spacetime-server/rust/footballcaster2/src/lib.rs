@@ -1,12 +1,70 @@
- use spacetimedb::{reducer, table, ReducerContext, Table, DbContext};
+ use spacetimedb::{reducer, table, Identity, ReducerContext, ScheduleAt, Table, Timestamp, DbContext};
+use spacetimedb::rand::Rng;
  use serde::{Deserialize, Serialize};
+
+mod error;
+mod competition;
+mod sim;
+mod starter;
+mod wei;
+
+pub use error::ReducerError;
+use wei::u256;
  
  pub const HOLD_DAYS: i64 = 7;
+ // Default anti-snipe policy: one extension of ANTI_SNIPE_EXTEND_SECONDS for bids in the final window
  pub const ANTI_SNIPE_EXTEND_SECONDS: i64 = 180;
- pub const DEV_FID: i64 = 250704;
+pub const ANTI_SNIPE_WINDOW_SECONDS: i64 = 180;
+pub const ANTI_SNIPE_MAX_EXTENSIONS: i32 = 1;
+// Cancelling an auction that already has bids costs the seller this share of the
+// top bid (basis points), paid to the top bidder
+pub const AUCTION_CANCEL_PENALTY_BPS: u64 = 500;
+// Default market_limits when an item type has no row: squads need longer auctions
+pub const AUCTION_MIN_SECONDS: i64 = 60 * 60;
+pub const AUCTION_MAX_SECONDS: i64 = 7 * 24 * 60 * 60;
+pub const SQUAD_AUCTION_MIN_SECONDS: i64 = 24 * 60 * 60;
+pub const SQUAD_AUCTION_MAX_SECONDS: i64 = 14 * 24 * 60 * 60;
+pub const MIN_LISTING_PRICE_WEI: u128 = 1_000_000_000_000_000_000; // 1 FBC
+// A buyer's purchase intent freezes the listing price while they pay on-chain
+pub const LISTING_PURCHASE_WINDOW_SECONDS: i64 = 600;
+// Unconfirmed PvP reports finalize as reported after this long
+pub const PVP_CONFIRM_HOURS: i64 = 24;
+// Defaults for pvp_config: unanswered challenges expire, results-less matches are abandoned
+pub const PVP_CHALLENGE_EXPIRY_HOURS: i64 = 48;
+pub const PVP_ACTIVE_DEADLINE_HOURS: i64 = 72;
+// ELO: new managers move fast until they have played ELO_PROVISIONAL_GAMES
+pub const ELO_START: i32 = 1000;
+pub const ELO_FLOOR: i32 = 100;
+pub const ELO_PROVISIONAL_GAMES: usize = 10;
+pub const ELO_K_PROVISIONAL: i32 = 40;
+pub const ELO_K_ESTABLISHED: i32 = 20;
+pub const ELO_K_ELITE: i32 = 10;
+pub const ELO_ELITE_RATING: i32 = 2400;
+// Matchmaking: the acceptable ELO gap widens the longer a manager waits
+pub const MM_TICK_SECONDS: u64 = 10;
+pub const MM_BASE_WINDOW: i32 = 50;
+pub const MM_WINDOW_STEP: i32 = 25; // per MM_WINDOW_STEP_SECONDS waited
+pub const MM_WINDOW_STEP_SECONDS: i64 = 30;
+pub const MM_MAX_WINDOW: i32 = 400;
+pub const MM_NPC_FALLBACK_SECONDS: i64 = 120;
+// Leagues: division size and the spacing allowed between fixture rounds
+pub const LEAGUE_MIN_MEMBERS: usize = 2;
+pub const LEAGUE_MAX_MEMBERS: usize = 24;
+pub const LEAGUE_MAX_ROUND_INTERVAL_HOURS: i64 = 24 * 7;
+// A disputed league or cup match left unresolved this long is simulated
+pub const FIXTURE_DISPUTE_HOURS: i64 = 48;
+// Cups: entrant limits, group stage shape and the default prize split
+// (champion, runner-up) in basis points
+pub const CUP_MIN_ENTRANTS: u32 = 2;
+pub const CUP_MAX_ENTRANTS: u32 = 64;
+pub const CUP_GROUP_SIZE: usize = 4;
+pub const CUP_GROUP_ADVANCE: usize = 2;
+pub const CUP_DEFAULT_PRIZE_SPLIT_BPS: [u32; 2] = [7000, 3000];
+pub const PLAYER_RECOVER_TICK_HOURS: u64 = 6;
+pub const SEASON_DAYS: u64 = 90;
  
  fn now_ms(ctx: &ReducerContext) -> i64 {
-     (ctx.timestamp.to_micros_since_unix_epoch() / 1000) as i64
+     ctx.timestamp.to_micros_since_unix_epoch() / 1000
  }
  
  fn new_id(ctx: &ReducerContext, kind: &str, extra: &str) -> String {
@@ -16,7 +74,6 @@
  
  fn normalize(addr: &str) -> String { addr.to_lowercase() }
  
- fn parse_wei(x: Option<&str>) -> i128 { x.and_then(|s| s.parse::<i128>().ok()).unwrap_or(0) }
  
 #[table(name = user, public)]
 #[derive(Clone, Serialize, Deserialize)]
@@ -40,6 +97,46 @@ pub struct User {
      pub fid: i64,
      pub linked_at_ms: i64,
  }
+
+// Binds a client identity to the fid it acts as. Mutating reducers resolve their
+// actor from here rather than trusting a fid argument.
+#[table(name = identity_link, public)]
+#[derive(Clone)]
+pub struct IdentityLink {
+    #[primary_key]
+    pub identity: Identity,
+    #[index(btree)]
+    pub fid: i64,
+    pub method: String, // sign_in (older rows: wallet)
+    pub linked_at_ms: i64,
+}
+
+// Operator roles for privileged reducers. Composite key simulated via synthetic id.
+// admin: everything, incl. granting roles and hold exemptions
+// backend: our Next.js service; may act on behalf of any fid after verifying it
+// moderator: officials and match oversight
+#[table(name = operator_role, public)]
+#[derive(Clone)]
+pub struct OperatorRole {
+    #[primary_key]
+    pub id: String, // format: "{identity}:{role}"
+    #[index(btree)]
+    pub identity: Identity,
+    pub role: String, // admin|backend|moderator
+    pub granted_by: Identity,
+    pub granted_at_ms: i64,
+}
+
+// Fids allowed to list or auction items before their hold expires (e.g. house
+// accounts). Keyed by the acting fid, never by the calling identity.
+#[table(name = hold_exemption, public)]
+#[derive(Clone)]
+pub struct HoldExemption {
+    #[primary_key]
+    pub fid: i64,
+    pub granted_by: Identity,
+    pub granted_at_ms: i64,
+}
  
  #[table(name = starter_claim, public)]
  #[derive(Clone, Serialize, Deserialize)]
@@ -54,6 +151,7 @@ pub struct User {
  pub struct InventoryItem {
      #[primary_key]
      pub item_id: String,
+    #[index(btree)]
      pub owner_fid: i64,
     // Allowed values: "player", "npc_manager", "squad"
     pub item_type: String,
@@ -68,10 +166,14 @@ pub struct User {
 pub struct NpcRegistry {
     #[primary_key]
     pub npc_fid: i64, // references user.fid (is_npc=true)
-    pub token_id: Option<String>,
+    // Inventory item id once minted, "" before. Not an Option so it can be indexed.
+    #[index(btree)]
+    pub token_id: String,
     pub ai_seed: i64,
+    #[index(btree)]
     pub difficulty_tier: i16,
-    pub budget_fbc_wei: String,
+    #[serde(with = "wei::decimal")]
+    pub budget_fbc_wei: u256,
     pub persona: String,
     pub owner_fid: Option<i64>,
     // Emotions/state
@@ -106,11 +208,59 @@ pub struct SquadRegistry {
     pub intelligence_score: i32, // 0..100
     pub rank: String,            // S/A/B/C/D
     pub persona: String,
+    #[index(btree)]
     pub token_id: String,        // inventory item id for squad
     pub owner_fid: i64,
     pub active: bool,
 }
 
+// Canonical card for a player item, written when the player is minted
+#[table(name = player_card, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PlayerCard {
+    #[primary_key]
+    pub item_id: String, // refers to inventory_item.item_id
+    pub name: String,
+    pub positions: Vec<String>, // primary first: GK|DEF|MID|FWD
+    pub overall: i32,           // 0..100
+    // Attributes 0..100
+    pub pace: i32,
+    pub shooting: i32,
+    pub passing: i32,
+    pub defending: i32,
+    pub physical: i32,
+    pub goalkeeping: i32,
+    pub preferred_foot: String, // left|right|both
+    pub potential: i32,
+    pub created_at_ms: i64,
+}
+
+// A manager's team sheet. Starters and substitutes live in lineup_slot.
+#[table(name = lineup, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Lineup {
+    #[primary_key]
+    pub fid: i64,
+    pub formation: String,       // one of FORMATIONS, e.g. "4-3-3"
+    pub captain_item_id: String, // "" = no captain; always a starter
+    pub updated_at_ms: i64,
+}
+
+// Slots 0..=10 are starters in formation order (GK, defence, midfield, attack);
+// LINEUP_FIRST_SUB_SLOT and up are substitutes
+#[table(name = lineup_slot, public, index(name = fid_slot, btree(columns = [fid, slot])))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct LineupSlot {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub fid: i64,
+    pub slot: u8,
+    pub position: String, // GK|DEF|MID|FWD for starters, SUB for substitutes
+    #[index(btree)]
+    pub item_id: String,
+}
+
 // Player human-like state tracked over time (for item_type == "player")
 #[table(name = player_state, public)]
 #[derive(Clone, Serialize, Deserialize)]
@@ -129,6 +279,195 @@ pub struct PlayerState {
     pub matches_played_7d: i32,
     pub matches_benched_7d: i32,
     pub last_match_at_ms: Option<i64>,
+    // Cumulative age-related decline 0..100, applied when rating the player
+    pub age_decline: i32,
+}
+
+// One row per player appearance, kept for 7 days so the rolling counters on
+// PlayerState can be rolled off exactly.
+#[table(name = player_match_log)]
+#[derive(Clone)]
+pub struct PlayerMatchLog {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub player_id: String,
+    pub minutes_played: i32,
+    pub benched: bool,
+    #[index(btree)]
+    pub ts_ms: i64,
+}
+
+#[table(name = player_recover_schedule, scheduled(player_state_recover_tick))]
+pub struct PlayerRecoverSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+#[table(name = player_age_schedule, scheduled(player_age_tick))]
+pub struct PlayerAgeSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+// A division of a league pyramid; division 1 is the top flight
+#[table(name = league, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct League {
+    #[primary_key]
+    pub id: String,
+    #[index(btree)]
+    pub pyramid: String,
+    pub name: String,
+    pub division: u32,
+    pub promotion_slots: u32,  // top finishers moving up a division
+    pub relegation_slots: u32, // bottom finishers moving down a division
+    pub created_at_ms: i64,
+}
+
+// Current division of each manager; promotion and relegation rewrite league_id
+#[table(name = league_member, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct LeagueMember {
+    #[primary_key]
+    pub fid: i64,
+    #[index(btree)]
+    pub league_id: String,
+    pub is_npc: bool,
+    pub joined_at_ms: i64,
+}
+
+// One campaign across every division of a pyramid
+#[table(name = season, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Season {
+    #[primary_key]
+    pub id: String,
+    #[index(btree)]
+    pub pyramid: String,
+    pub number: u32,
+    pub status: String, // active|finished
+    pub mode: String,   // sim: every fixture is simulated | played: human pairings play a PvP match
+    pub round_interval_hours: i64,
+    pub started_at_ms: i64,
+    pub finished_at_ms: Option<i64>,
+}
+
+#[table(name = fixture, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Fixture {
+    #[primary_key]
+    pub id: String,
+    #[index(btree)]
+    pub season_id: String,
+    pub league_id: String,
+    pub round: u32,
+    pub home_fid: i64,
+    pub away_fid: i64,
+    pub kickoff_at_ms: i64,
+    pub status: String, // scheduled|in_play|finished
+    #[index(btree)]
+    pub match_id: String, // linked pvp_match while played as PvP; "" otherwise
+    pub home_goals: Option<i64>,
+    pub away_goals: Option<i64>,
+    pub finished_at_ms: Option<i64>,
+}
+
+// League table row per manager per season (id = "{season_id}:{fid}")
+#[table(name = standing, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Standing {
+    #[primary_key]
+    pub id: String,
+    #[index(btree)]
+    pub season_id: String,
+    pub league_id: String,
+    pub fid: i64,
+    pub played: u32,
+    pub won: u32,
+    pub drawn: u32,
+    pub lost: u32,
+    pub goals_for: i64,
+    pub goals_against: i64,
+    pub points: i32,
+    pub form: String, // last results, most recent last, e.g. "WDLWW"
+}
+
+#[table(name = fixture_kickoff, scheduled(fixture_kickoff_tick))]
+pub struct FixtureKickoff {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    pub fixture_id: String,
+}
+
+// Knockout cup played over PvP matches, optionally opening with a group stage
+#[table(name = cup, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Cup {
+    #[primary_key]
+    pub id: String,
+    pub name: String,
+    pub status: String, // registration|group_stage|knockout|finished|cancelled
+    #[serde(with = "wei::decimal")]
+    pub entry_fee_wei: u256,
+    #[serde(with = "wei::decimal")]
+    pub prize_pool_wei: u256, // entry fees collected so far
+    pub max_entrants: u32,
+    pub group_stage: bool,
+    // Pool share (bps) by finish: champion, runner-up, semi-final losers, quarter-final losers
+    pub prize_split_bps: Vec<u32>,
+    pub knockout_rounds: u32, // set when the bracket is drawn
+    pub current_round: u32,   // knockout round in play; 0 before the bracket
+    pub winner_fid: Option<i64>,
+    pub created_at_ms: i64,
+    pub started_at_ms: Option<i64>,
+    pub finished_at_ms: Option<i64>,
+}
+
+#[table(name = cup_entry, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CupEntry {
+    #[primary_key]
+    pub id: String, // "{cup_id}:{fid}"
+    #[index(btree)]
+    pub cup_id: String,
+    pub fid: i64,
+    pub seed: u32,          // 1 = strongest by ELO, assigned at the draw
+    pub group_name: String, // "A", "B", ...; "" without a group stage
+    pub status: String,     // active|eliminated|champion
+    pub eliminated_round: Option<u32>, // knockout round lost in; 0 = group stage
+    pub entered_at_ms: i64,
+}
+
+// A group fixture or knockout tie; a knockout tie without an away side is a bye
+#[table(name = cup_tie, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CupTie {
+    #[primary_key]
+    pub id: String,
+    #[index(btree)]
+    pub cup_id: String,
+    pub stage: String, // group|knockout
+    pub round: u32,    // group matchday or knockout round (1 = first)
+    pub slot: u32,     // bracket position within the round
+    pub group_name: String,
+    pub home_fid: i64,
+    pub away_fid: Option<i64>,
+    pub status: String, // in_play|finished
+    #[index(btree)]
+    pub match_id: String, // linked pvp_match; "" for byes
+    pub home_goals: Option<i64>, // including extra time
+    pub away_goals: Option<i64>,
+    pub penalties_json: Option<String>, // {"home":n,"away":n} after a shoot-out
+    pub decided_by: String, // normal|extra_time|penalties|bye; "" while in play
+    pub winner_fid: Option<i64>, // None for a drawn group fixture
+    pub finished_at_ms: Option<i64>,
 }
 
 // Match officials (referee crew & VAR)
@@ -183,13 +522,30 @@ pub struct CommentaryLog {
  pub struct Listing {
      #[primary_key]
      pub id: String,
+    #[index(btree)]
      pub item_id: String,
      pub seller_fid: i64,
-     pub price_wei: String,
-     pub status: String,
+     #[serde(with = "wei::decimal")]
+     pub price_wei: u256,
+     pub status: String, // active|closed|cancelled
      pub created_at_ms: i64,
      pub closed_at_ms: Option<i64>,
+    // Set by listing_reserve_purchase; the seller cannot reprice or cancel until it lapses
+    pub pending_buyer_fid: Option<i64>,
+    pub pending_until_ms: Option<i64>,
  }
+
+// One purchase window per buyer per listing; windows cannot be renewed
+#[table(name = listing_reservation, public)]
+#[derive(Clone)]
+pub struct ListingReservation {
+    #[primary_key]
+    pub id: String, // "{listing_id}:{buyer_fid}"
+    #[index(btree)]
+    pub listing_id: String,
+    pub buyer_fid: i64,
+    pub reserved_at_ms: i64,
+}
  
  #[table(name = auction, public)]
  #[derive(Clone, Serialize, Deserialize)]
@@ -198,27 +554,98 @@ pub struct CommentaryLog {
      pub id: String,
      pub item_id: String,
      pub seller_fid: i64,
-     pub reserve_wei: String,
+     #[serde(with = "wei::decimal")]
+     pub reserve_wei: u256,
      pub ends_at_ms: i64,
-     pub status: String,
-     pub top_bid_wei: Option<String>,
+     pub status: String, // active|finalized|expired|reserve_not_met|cancelled
+     #[serde(with = "wei::decimal_opt")]
+     pub top_bid_wei: Option<u256>,
      pub top_bidder_fid: Option<i64>,
-     pub buy_now_wei: Option<String>,
+     #[serde(with = "wei::decimal_opt")]
+     pub buy_now_wei: Option<u256>,
      pub anti_snipe_used: bool,
      pub created_at_ms: i64,
      pub finalized_at_ms: Option<i64>,
+    // Anti-snipe policy: a bid within `window` of the end pushes it out by `extend`,
+    // at most `max_extensions` times and never past `max_ends_at_ms`
+    pub anti_snipe_window_seconds: i64,
+    pub anti_snipe_extend_seconds: i64,
+    pub anti_snipe_max_extensions: i32,
+    pub anti_snipe_extensions: i32,
+    pub max_ends_at_ms: Option<i64>,
  }
+
+// Seller-supplied anti-snipe settings for create_auction; omitted fields use the defaults
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct AntiSnipePolicy {
+    pub window_seconds: i64,
+    pub extend_seconds: i64,
+    pub max_extensions: i32,
+    // Hard cap on the total time added to the original end
+    pub max_total_extension_seconds: Option<i64>,
+}
+
+impl Default for AntiSnipePolicy {
+    fn default() -> Self {
+        AntiSnipePolicy {
+            window_seconds: ANTI_SNIPE_WINDOW_SECONDS,
+            extend_seconds: ANTI_SNIPE_EXTEND_SECONDS,
+            max_extensions: ANTI_SNIPE_MAX_EXTENSIONS,
+            max_total_extension_seconds: None,
+        }
+    }
+}
+ 
+// An item reserved by an open listing or auction. Only that sale may transfer it.
+#[table(name = item_lock, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ItemLock {
+    #[primary_key]
+    pub item_id: String,
+    pub sale_kind: String, // listing|auction
+    pub sale_id: String,
+    pub seller_fid: i64,
+    pub locked_at_ms: i64,
+}
+
+// One-shot timer that settles an auction at `ends_at_ms`. If an anti-snipe bid has
+// pushed the end out, the tick re-arms itself for the new end time.
+#[table(name = auction_timer, scheduled(auction_close_tick))]
+pub struct AuctionTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    pub auction_id: String,
+}
  
  #[table(name = bid, public)]
  #[derive(Clone, Serialize, Deserialize)]
  pub struct Bid {
      #[primary_key]
      pub id: String,
+    #[index(btree)]
      pub auction_id: String,
      pub fid: i64,
-     pub amount_wei: String,
+     #[serde(with = "wei::decimal")]
+     pub amount_wei: u256,
      pub placed_at_ms: i64,
  }
+
+// Registered maximum bids. Private: only the effective Bid rows they produce are public.
+#[table(name = proxy_bid)]
+#[derive(Clone)]
+pub struct ProxyBid {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub auction_id: String,
+    pub fid: i64,
+    pub max_wei: u256,
+    pub placed_at_ms: i64, // ties between equal caps go to the earliest
+}
  
  #[table(name = event, public)]
  #[derive(Clone, Serialize, Deserialize)]
@@ -228,7 +655,9 @@ pub struct CommentaryLog {
      pub ts_ms: i64,
      pub kind: String,
      pub actor_fid: i64,
-     pub topic_id: Option<String>,
+    // "" for events without a topic. Not an Option so it can be indexed.
+    #[index(btree)]
+     pub topic_id: String,
      pub payload_json: String,
  }
  
@@ -237,6 +666,7 @@ pub struct CommentaryLog {
  pub struct Inbox {
      #[primary_key]
      pub msg_id: String,
+    #[index(btree)]
      pub fid: i64,
      pub kind: String,
      pub title: String,
@@ -245,18 +675,130 @@ pub struct CommentaryLog {
      pub read_at_ms: Option<i64>,
  }
  
- #[table(name = pvp_match, public)]
+ #[table(name = pvp_match, public, index(name = pair_status, btree(columns = [challenger_fid, challenged_fid, status])))]
  #[derive(Clone, Serialize, Deserialize)]
  pub struct PvpMatch {
      #[primary_key]
      pub id: String,
      pub challenger_fid: i64,
+    // pair_status covers lookups by challenger; this one covers the other side
+    #[index(btree)]
      pub challenged_fid: i64,
-     pub status: String, // pending, active, finalized
+     pub status: String, // pending, active, awaiting_confirmation, disputed, finalized, cancelled, declined, expired, abandoned
      pub created_at_ms: i64,
      pub accepted_at_ms: Option<i64>,
      pub result_json: Option<String>,
+    // First result report, awaiting the opponent's confirmation
+    pub reported_by_fid: Option<i64>,
+    pub reported_at_ms: Option<i64>,
+    pub finalized_at_ms: Option<i64>,
  }
+
+// Conflicting PvP reports; one per match, resolved by an operator or the simulator
+#[table(name = pvp_dispute, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PvpDispute {
+    #[primary_key]
+    pub match_id: String,
+    pub opened_by_fid: i64,
+    pub reason: String,
+    pub reported_result_json: String, // the first report
+    pub counter_result_json: Option<String>, // the opponent's report, if they sent one
+    pub status: String, // open|resolved
+    pub resolution: Option<String>, // operator|sim
+    pub resolved_result_json: Option<String>,
+    pub opened_at_ms: i64,
+    pub resolved_at_ms: Option<i64>,
+}
+
+// Operator-tunable PvP timeouts (single row, id = 0)
+#[table(name = pvp_config, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PvpConfig {
+    #[primary_key]
+    pub id: u32,
+    pub challenge_expiry_hours: i64,
+    pub active_deadline_hours: i64,
+    pub updated_at_ms: i64,
+}
+
+// Operator-tunable market limits per item type (player|squad|npc_manager)
+#[table(name = market_limits, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MarketLimits {
+    #[primary_key]
+    pub item_type: String,
+    pub min_auction_seconds: i64,
+    pub max_auction_seconds: i64,
+    // Floor for listing prices, buy-now prices and non-zero reserves
+    #[serde(with = "wei::decimal")]
+    pub min_price_wei: u256,
+    pub updated_at_ms: i64,
+}
+
+// One-shot timer per match phase: "expire" a pending challenge, "abandon" an
+// active match that never got a result, or settle a "dispute" holding up a
+// league fixture or cup tie. Stale timers are ignored by the tick.
+#[table(name = pvp_match_timer, scheduled(pvp_match_timeout_tick))]
+pub struct PvpMatchTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    pub match_id: String,
+    pub kind: String, // expire|abandon|dispute
+}
+
+// Managers waiting for a PvP opponent
+#[table(name = matchmaking_queue, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MatchmakingQueue {
+    #[primary_key]
+    pub fid: i64,
+    pub elo: i32,
+    pub enqueued_at_ms: i64,
+}
+
+// Recurring pairing pass; armed on enqueue, dropped once the queue drains
+#[table(name = matchmaking_schedule, scheduled(matchmaking_tick))]
+pub struct MatchmakingSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+// Per-match ELO change for one participant
+#[table(name = rating_history, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RatingHistory {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub fid: i64,
+    pub match_id: String,
+    pub opponent_fid: i64,
+    pub opponent_elo: i32,
+    pub result: String, // win|draw|loss
+    pub elo_before: i32,
+    pub elo_after: i32,
+    pub delta: i32,
+    pub k_factor: i32,
+    pub provisional: bool,
+    pub ts_ms: i64,
+}
+
+// One-shot timer that finalizes a reported PvP result nobody confirmed or disputed.
+#[table(name = pvp_confirm_timer, scheduled(pvp_confirm_timeout_tick))]
+pub struct PvpConfirmTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    pub match_id: String,
+    pub reported_at_ms: i64,
+}
  
  #[table(name = idempotency, public)]
  #[derive(Clone, Serialize, Deserialize)]
@@ -279,47 +821,99 @@ pub struct CommentaryLog {
      pub endpoint: String,
  }
  
+// Internal FBC balance per fid. `escrow_wei` is locked behind open bids.
+#[table(name = balance, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Balance {
+    #[primary_key]
+    pub fid: i64,
+    #[serde(with = "wei::decimal")]
+    pub available_wei: u256,
+    #[serde(with = "wei::decimal")]
+    pub escrow_wei: u256,
+    pub updated_at_ms: i64,
+}
+
+// Append-only record of every balance movement
+#[table(name = ledger_entry, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub fid: i64,
+    pub kind: String,      // deposit|withdraw|escrow_lock|escrow_release|settle
+    pub direction: String, // credit|debit (for escrow moves: relative to available)
+    #[serde(with = "wei::decimal")]
+    pub amount_wei: u256,
+    pub counterparty_fid: Option<i64>,
+    pub ref_id: String, // tx hash, auction id or listing id
+    pub ts_ms: i64,
+}
+
  #[derive(Serialize, Deserialize, Clone, Default)]
  pub struct StarterPlayer { pub player_id: String, pub name: Option<String>, pub position: Option<String>, pub rating: Option<i32> }
  
  #[derive(Serialize, Deserialize, Clone, Default)]
- pub struct StarterPackPayload { pub players: Vec<StarterPlayer> }
+ pub struct StarterPackPayload {
+    pub players: Vec<StarterPlayer>,
+    // Generator seed as a decimal string (u64 does not survive JS numbers)
+    #[serde(default)]
+    pub seed: String,
+}
  
  fn push_inbox(ctx: &ReducerContext, fid: i64, msg_id: String, ty: &str, title: &str, body: &str) {
      ctx.db().inbox().insert(Inbox { msg_id, fid, kind: ty.to_string(), title: title.to_string(), body: body.to_string(), created_at_ms: now_ms(ctx), read_at_ms: None });
  }
  
- fn transfer_item(ctx: &ReducerContext, item_id: &str, from_fid: i64, to_fid: i64, event_id: &str) -> Result<(), String> {
+ /// Moves an item as the result of `sale_id`. Only the sale holding the item's
+ /// lock may move it; the lock is released with the transfer.
+ fn transfer_item(ctx: &ReducerContext, item_id: &str, from_fid: i64, to_fid: i64, event_id: &str, sale_id: &str) -> Result<(), ReducerError> {
      let by_pk = ctx.db().inventory_item().item_id();
-     let item = by_pk.find(&item_id.to_string()).ok_or("item_not_found")?;
-     if item.owner_fid != from_fid { return Err("not_owner".into()); }
+     let item = by_pk.find(item_id.to_string()).ok_or(ReducerError::ItemNotFound)?;
+     if item.owner_fid != from_fid { return Err(ReducerError::NotOwner); }
+    if let Some(lock) = ctx.db().item_lock().item_id().find(item_id.to_string()) {
+        if lock.sale_id != sale_id { return Err(ReducerError::ItemLocked); }
+    }
+    release_item_lock(ctx, item_id, sale_id);
      by_pk.update(InventoryItem { owner_fid: to_fid, acquired_at_ms: now_ms(ctx), source_event_id: event_id.to_string(), ..item });
      Ok(())
  }
 
+/// Reserves an item for one listing or auction at a time.
+fn lock_item(ctx: &ReducerContext, item_id: &str, sale_kind: &str, sale_id: &str, seller_fid: i64) -> Result<(), ReducerError> {
+    let locks = ctx.db().item_lock();
+    if locks.item_id().find(item_id.to_string()).is_some() { return Err(ReducerError::ItemLocked); }
+    locks.insert(ItemLock { item_id: item_id.to_string(), sale_kind: sale_kind.to_string(), sale_id: sale_id.to_string(), seller_fid, locked_at_ms: now_ms(ctx) });
+    Ok(())
+}
+
+/// Drops the lock if `sale_id` still holds it; a no-op otherwise.
+fn release_item_lock(ctx: &ReducerContext, item_id: &str, sale_id: &str) {
+    let locks = ctx.db().item_lock();
+    if locks.item_id().find(item_id.to_string()).is_some_and(|l| l.sale_id == sale_id) {
+        locks.item_id().delete(item_id.to_string());
+    }
+}
+
 fn on_item_transferred(ctx: &ReducerContext, item_id: &str, to_fid: i64) {
+    remove_from_lineups(ctx, item_id);
     // Update owner in npc_registry or squad_registry based on inventory_item.item_type
-    if let Some(item) = ctx.db().inventory_item().item_id().find(&item_id.to_string()) {
+    if let Some(item) = ctx.db().inventory_item().item_id().find(item_id.to_string()) {
         match item.item_type.as_str() {
             "npc_manager" => {
-                // find npc by token_id
                 let tbl = ctx.db().npc_registry();
-                for mut n in tbl.iter() {
-                    if n.token_id.as_deref() == Some(&item_id.to_string()) {
-                        n.owner_fid = Some(to_fid);
-                        tbl.npc_fid().update(n);
-                        break;
-                    }
+                if let Some(mut n) = tbl.token_id().filter(item_id).next() {
+                    n.owner_fid = Some(to_fid);
+                    tbl.npc_fid().update(n);
                 }
             }
             "squad" => {
                 let tbl = ctx.db().squad_registry();
-                for mut s in tbl.iter() {
-                    if s.token_id == item_id {
-                        s.owner_fid = to_fid;
-                        tbl.squad_id().update(s);
-                        break;
-                    }
+                if let Some(mut s) = tbl.token_id().filter(item_id).next() {
+                    s.owner_fid = to_fid;
+                    tbl.squad_id().update(s);
                 }
             }
             _ => {}
@@ -327,41 +921,276 @@ fn on_item_transferred(ctx: &ReducerContext, item_id: &str, to_fid: i64) {
     }
 }
  
+fn load_balance(ctx: &ReducerContext, fid: i64) -> Balance {
+    ctx.db().balance().fid().find(fid).unwrap_or(Balance { fid, available_wei: u256::ZERO, escrow_wei: u256::ZERO, updated_at_ms: now_ms(ctx) })
+}
+
+fn store_balance(ctx: &ReducerContext, mut b: Balance) {
+    b.updated_at_ms = now_ms(ctx);
+    let tbl = ctx.db().balance();
+    if tbl.fid().find(b.fid).is_some() { tbl.fid().update(b); } else { tbl.insert(b); }
+}
+
+fn record_ledger(ctx: &ReducerContext, fid: i64, kind: &str, direction: &str, amount: u256, counterparty_fid: Option<i64>, ref_id: &str) {
+    ctx.db().ledger_entry().insert(LedgerEntry {
+        id: 0,
+        fid,
+        kind: kind.to_string(),
+        direction: direction.to_string(),
+        amount_wei: amount,
+        counterparty_fid,
+        ref_id: ref_id.to_string(),
+        ts_ms: now_ms(ctx),
+    });
+}
+
+fn credit_available(ctx: &ReducerContext, fid: i64, amount: u256) -> Result<(), ReducerError> {
+    let mut b = load_balance(ctx, fid);
+    b.available_wei = wei::add(b.available_wei, amount)?;
+    store_balance(ctx, b);
+    Ok(())
+}
+
+fn debit_available(ctx: &ReducerContext, fid: i64, amount: u256) -> Result<(), ReducerError> {
+    let mut b = load_balance(ctx, fid);
+    b.available_wei = wei::sub(b.available_wei, amount, ReducerError::InsufficientFunds)?;
+    store_balance(ctx, b);
+    Ok(())
+}
+
+fn escrow_lock(ctx: &ReducerContext, fid: i64, amount: u256, ref_id: &str) -> Result<(), ReducerError> {
+    if amount == u256::ZERO { return Err(ReducerError::InvalidAmount); }
+    let mut b = load_balance(ctx, fid);
+    b.available_wei = wei::sub(b.available_wei, amount, ReducerError::InsufficientFunds)?;
+    b.escrow_wei = wei::add(b.escrow_wei, amount)?;
+    store_balance(ctx, b);
+    record_ledger(ctx, fid, "escrow_lock", "debit", amount, None, ref_id);
+    Ok(())
+}
+
+fn escrow_release(ctx: &ReducerContext, fid: i64, amount: u256, ref_id: &str) -> Result<(), ReducerError> {
+    if amount == u256::ZERO { return Ok(()); }
+    let mut b = load_balance(ctx, fid);
+    b.escrow_wei = wei::sub(b.escrow_wei, amount, ReducerError::EscrowUnderflow)?;
+    b.available_wei = wei::add(b.available_wei, amount)?;
+    store_balance(ctx, b);
+    record_ledger(ctx, fid, "escrow_release", "credit", amount, None, ref_id);
+    Ok(())
+}
+
+/// Moves `amount` from payer to payee, taken from the payer's escrow when the
+/// funds were locked by a bid, otherwise from their available balance.
+fn settle_payment(ctx: &ReducerContext, payer_fid: i64, payee_fid: i64, amount: u256, ref_id: &str, from_escrow: bool) -> Result<(), ReducerError> {
+    if amount == u256::ZERO { return Err(ReducerError::InvalidAmount); }
+    if from_escrow {
+        let mut b = load_balance(ctx, payer_fid);
+        b.escrow_wei = wei::sub(b.escrow_wei, amount, ReducerError::EscrowUnderflow)?;
+        store_balance(ctx, b);
+    } else {
+        debit_available(ctx, payer_fid, amount)?;
+    }
+    credit_available(ctx, payee_fid, amount)?;
+    record_ledger(ctx, payer_fid, "settle", "debit", amount, Some(payee_fid), ref_id);
+    record_ledger(ctx, payee_fid, "settle", "credit", amount, Some(payer_fid), ref_id);
+    Ok(())
+}
+
+/// Returns the current top bidder's locked funds (outbid, buy-now or expiry).
+fn refund_top_bid(ctx: &ReducerContext, a: &Auction) -> Result<(), ReducerError> {
+    if let (Some(fid), Some(amount)) = (a.top_bidder_fid, a.top_bid_wei) {
+        escrow_release(ctx, fid, amount, &a.id)?;
+    }
+    Ok(())
+}
+
  fn append_event(ctx: &ReducerContext, ty: &str, actor_fid: i64, payload_json: String, topic_id: Option<String>) -> Event {
      let id = new_id(ctx, "evt", &format!("{}:{}:{:?}", ty, actor_fid, topic_id));
-     let e = Event { id: id.clone(), ts_ms: now_ms(ctx), kind: ty.to_string(), actor_fid, topic_id, payload_json };
+     let e = Event { id: id.clone(), ts_ms: now_ms(ctx), kind: ty.to_string(), actor_fid, topic_id: topic_id.unwrap_or_default(), payload_json };
      ctx.db().event().insert(e.clone());
      e
  }
  
+fn role_id(identity: Identity, role: &str) -> String { format!("{}:{}", identity, role) }
+
+/// True if the caller holds `role`. Admins implicitly hold every role.
+fn has_role(ctx: &ReducerContext, role: &str) -> bool {
+    let roles = ctx.db().operator_role();
+    roles.id().find(role_id(ctx.sender, role)).is_some() || roles.id().find(role_id(ctx.sender, "admin")).is_some()
+}
+
+fn require_role(ctx: &ReducerContext, allowed: &[&str]) -> Result<(), ReducerError> {
+    if allowed.iter().any(|r| has_role(ctx, r)) { Ok(()) } else { Err(ReducerError::Forbidden) }
+}
+
+fn is_trusted_service(ctx: &ReducerContext) -> bool { has_role(ctx, "backend") }
+
+/// Rejects trading an item still in its hold unless the acting fid is exempt.
+fn check_hold(hold_until_ms: i64, now_ms: i64, fid_exempt: bool) -> Result<(), ReducerError> {
+    if now_ms < hold_until_ms && !fid_exempt { Err(ReducerError::InHold) } else { Ok(()) }
+}
+
+fn is_hold_exempt(ctx: &ReducerContext, fid: i64) -> bool {
+    ctx.db().hold_exemption().fid().find(fid).is_some()
+}
+
+fn linked_fid(ctx: &ReducerContext) -> Option<i64> {
+    ctx.db().identity_link().identity().find(ctx.sender).map(|l| l.fid)
+}
+
+/// Resolves the fid the caller acts as. Linked clients always act as their own fid
+/// (a differing argument is rejected); backend operators act as the claimed fid.
+fn resolve_actor(ctx: &ReducerContext, claimed_fid: i64) -> Result<i64, ReducerError> {
+    if is_trusted_service(ctx) { return Ok(claimed_fid); }
+    let fid = linked_fid(ctx).ok_or(ReducerError::IdentityNotLinked)?;
+    if fid != claimed_fid { return Err(ReducerError::FidMismatch); }
+    Ok(fid)
+}
+
+fn bind_identity(ctx: &ReducerContext, identity: Identity, fid: i64, method: &str) {
+    let links = ctx.db().identity_link();
+    let link = IdentityLink { identity, fid, method: method.to_string(), linked_at_ms: now_ms(ctx) };
+    if links.identity().find(identity).is_some() { links.identity().update(link); } else { links.insert(link); }
+}
+
+fn schedule_auction_close(ctx: &ReducerContext, auction_id: &str, at_ms: i64) {
+    ctx.db().auction_timer().insert(AuctionTimer {
+        scheduled_id: 0,
+        scheduled_at: Timestamp::from_micros_since_unix_epoch(at_ms * 1000).into(),
+        auction_id: auction_id.to_string(),
+    });
+}
+
+/// Hands the item to the winning bidder and notifies both sides.
+fn settle_auction_sale(ctx: &ReducerContext, mut a: Auction, winner_fid: i64) -> Result<(), ReducerError> {
+    let evt = append_event(ctx, "AuctionFinalized", winner_fid, serde_json::to_string(&a).unwrap_or("{}".into()), Some(a.id.clone()));
+    a.status = "finalized".into();
+    a.finalized_at_ms = Some(now_ms(ctx));
+    ctx.db().auction().id().update(a.clone());
+    clear_proxy_bids(ctx, &a.id);
+    settle_payment(ctx, winner_fid, a.seller_fid, a.top_bid_wei.unwrap_or(u256::ZERO), &a.id, true)?;
+    transfer_item(ctx, &a.item_id, a.seller_fid, winner_fid, &evt.id, &a.id)?;
+    on_item_transferred(ctx, &a.item_id, winner_fid);
+    push_inbox(ctx, a.seller_fid, format!("auction-sold-{}", evt.id), "auction_sold", "Auction Sold!", "Your auction ended with a winning bid.");
+    push_inbox(ctx, winner_fid, format!("auction-won-{}", evt.id), "auction_won", "Auction Won", "You won an auction.");
+    Ok(())
+}
+
+/// Ends an auction without a sale: "expired" (no bids), "reserve_not_met" or
+/// "cancelled". The top bid is refunded, the seller keeps the item and every
+/// bidder is told.
+fn close_unsold_auction(ctx: &ReducerContext, mut a: Auction, status: &str) -> Result<(), ReducerError> {
+    refund_top_bid(ctx, &a)?;
+    let (kind, title, seller_body, bidder_body) = match status {
+        "reserve_not_met" => ("AuctionReserveNotMet", "Reserve Not Met", "Your auction ended below its reserve price.", "An auction you bid on ended below its reserve. Your funds were returned."),
+        "cancelled" => ("AuctionCancelled", "Auction Cancelled", "Your auction was cancelled.", "An auction you bid on was cancelled. Your funds were returned."),
+        _ => ("AuctionExpired", "Auction Ended", "Your auction ended without a qualifying bid.", ""),
+    };
+    let evt = append_event(ctx, kind, a.seller_fid, serde_json::to_string(&a).unwrap_or("{}".into()), Some(a.id.clone()));
+    a.status = status.into();
+    a.finalized_at_ms = Some(now_ms(ctx));
+    ctx.db().auction().id().update(a.clone());
+    clear_proxy_bids(ctx, &a.id);
+    release_item_lock(ctx, &a.item_id, &a.id);
+    let inbox_kind = format!("auction_{}", status);
+    push_inbox(ctx, a.seller_fid, format!("auction-{}-{}", status, evt.id), &inbox_kind, title, seller_body);
+    let mut bidders: Vec<i64> = ctx.db().bid().auction_id().filter(&a.id).map(|b| b.fid).collect();
+    bidders.sort_unstable();
+    bidders.dedup();
+    for fid in bidders {
+        push_inbox(ctx, fid, format!("auction-{}-{}-{}", status, evt.id, fid), &inbox_kind, title, bidder_body);
+    }
+    Ok(())
+}
+
+/// Settles an auction whose end time has passed.
+fn close_ended_auction(ctx: &ReducerContext, a: Auction) -> Result<(), ReducerError> {
+    match (a.top_bidder_fid, a.top_bid_wei) {
+        (Some(winner), Some(top)) if top >= a.reserve_wei => settle_auction_sale(ctx, a, winner),
+        (Some(_), Some(_)) => close_unsold_auction(ctx, a, "reserve_not_met"),
+        _ => close_unsold_auction(ctx, a, "expired"),
+    }
+}
+
  fn have_pending_pvp_between(ctx: &ReducerContext, a: i64, b: i64) -> bool {
-     // Naive scan for existing pending challenge between the same pair (either direction)
-     // Acceptable for small datasets; replace with indexed lookup if available in future.
-     let tbl = ctx.db().pvp_match();
-     // SAFETY: Table iteration is deterministic within reducer execution.
-     for m in tbl.iter() {
-         if m.status == "pending" &&
-            ((m.challenger_fid == a && m.challenged_fid == b) || (m.challenger_fid == b && m.challenged_fid == a)) {
-             return true;
-         }
-     }
-     false
+     // Existing pending challenge between the same pair (either direction)
+     let by_pair = ctx.db().pvp_match().pair_status();
+     by_pair.filter((a, b, "pending")).next().is_some() || by_pair.filter((b, a, "pending")).next().is_some()
  }
  
- fn validate_pvp_result_json(json: &str) -> Result<(), &'static str> {
-     let v: serde_json::Value = serde_json::from_str(json).map_err(|_| "invalid_json")?;
-     let home = v.get("home").and_then(|x| x.as_i64()).ok_or("missing_home")?;
-     let away = v.get("away").and_then(|x| x.as_i64()).ok_or("missing_away")?;
-     if home < 0 || away < 0 { return Err("negative_score"); }
-     if home > 20 || away > 20 { return Err("score_out_of_range"); }
-     Ok(())
+ /// Validates a reported result and returns its (home, away) score.
+ fn validate_pvp_result_json(json: &str) -> Result<(i64, i64), ReducerError> {
+     let v: serde_json::Value = serde_json::from_str(json).map_err(|_| ReducerError::InvalidJson)?;
+     let home = v.get("home").and_then(|x| x.as_i64()).ok_or(ReducerError::MissingHome)?;
+     let away = v.get("away").and_then(|x| x.as_i64()).ok_or(ReducerError::MissingAway)?;
+     if home < 0 || away < 0 { return Err(ReducerError::NegativeScore); }
+     if home > 20 || away > 20 { return Err(ReducerError::ScoreOutOfRange); }
+     Ok((home, away))
  }
  
+#[reducer(init)]
+pub fn init(ctx: &ReducerContext) -> Result<(), ReducerError> {
+    // The publishing identity becomes the first admin
+    ctx.db().operator_role().insert(OperatorRole { id: role_id(ctx.sender, "admin"), identity: ctx.sender, role: "admin".into(), granted_by: ctx.sender, granted_at_ms: now_ms(ctx) });
+    ensure_player_tick_schedules(ctx);
+    Ok(())
+}
+
+/// Starts the recurring player ticks on databases published before they existed.
+#[reducer]
+pub fn player_ticks_schedule(ctx: &ReducerContext) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend"])?;
+    ensure_player_tick_schedules(ctx);
+    Ok(())
+}
+
+#[reducer]
+pub fn operator_grant(ctx: &ReducerContext, identity: Identity, role: String) -> Result<(), ReducerError> {
+    require_role(ctx, &["admin"])?;
+    if !matches!(role.as_str(), "admin" | "backend" | "moderator") { return Err(ReducerError::InvalidRole); }
+    let roles = ctx.db().operator_role();
+    let id = role_id(identity, &role);
+    if roles.id().find(&id).is_some() { return Ok(()); }
+    roles.insert(OperatorRole { id, identity, role: role.clone(), granted_by: ctx.sender, granted_at_ms: now_ms(ctx) });
+    append_event(ctx, "operator_role_granted", 0, format!("{{\"identity\":\"{}\",\"role\":\"{}\"}}", identity, role), None);
+    Ok(())
+}
+
+#[reducer]
+pub fn operator_revoke(ctx: &ReducerContext, identity: Identity, role: String) -> Result<(), ReducerError> {
+    require_role(ctx, &["admin"])?;
+    let roles = ctx.db().operator_role();
+    let id = role_id(identity, &role);
+    if roles.id().find(&id).is_none() { return Ok(()); }
+    // Never lock the module out of administration
+    if role == "admin" && roles.iter().filter(|r| r.role == "admin").count() <= 1 { return Err(ReducerError::LastAdmin); }
+    roles.id().delete(&id);
+    append_event(ctx, "operator_role_revoked", 0, format!("{{\"identity\":\"{}\",\"role\":\"{}\"}}", identity, role), None);
+    Ok(())
+}
+
+/// Lets `fid` list and auction items while they are still in hold.
+#[reducer]
+pub fn hold_exemption_set(ctx: &ReducerContext, fid: i64, exempt: bool) -> Result<(), ReducerError> {
+    require_role(ctx, &["admin"])?;
+    let exemptions = ctx.db().hold_exemption();
+    match (exempt, exemptions.fid().find(fid).is_some()) {
+        (true, false) => { exemptions.insert(HoldExemption { fid, granted_by: ctx.sender, granted_at_ms: now_ms(ctx) }); }
+        (false, true) => { exemptions.fid().delete(fid); }
+        _ => return Ok(()),
+    }
+    append_event(ctx, "hold_exemption_set", fid, format!("{{\"fid\":{},\"exempt\":{}}}", fid, exempt), None);
+    Ok(())
+}
+
+/// Links a wallet to `fid`. Clients may only link wallets to the fid their
+/// identity is already bound to; binding itself happens only through the
+/// verified `identity_sign_in`.
  #[reducer]
- pub fn link_wallet(ctx: &ReducerContext, fid: i64, address: String) {
+ pub fn link_wallet(ctx: &ReducerContext, fid: i64, address: String) -> Result<(), ReducerError> {
+    let fid = resolve_actor(ctx, fid)?;
      let now = now_ms(ctx);
      let users = ctx.db().user();
-     match users.fid().find(&fid) {
+     match users.fid().find(fid) {
         Some(mut u) => { u.wallet = Some(address.clone()); users.fid().update(u); },
         None => {
             users.insert(User {
@@ -369,7 +1198,7 @@ fn on_item_transferred(ctx: &ReducerContext, item_id: &str, to_fid: i64) {
                 wallet: Some(address.clone()),
                 created_at_ms: now,
                 is_npc: false,
-                elo: 1000,
+                elo: ELO_START,
                 display_name: None,
                 ai_persona_json: None,
             });
@@ -379,182 +1208,819 @@ fn on_item_transferred(ctx: &ReducerContext, item_id: &str, to_fid: i64) {
      let wl = ctx.db().wallet_link();
      if wl.address().find(&link.address).is_some() { wl.address().delete(&link.address); }
      wl.insert(link);
+     Ok(())
  }
+
+/// Verified sign-in: the backend, having checked the user's Farcaster sign-in,
+/// binds the client's identity to `fid`, replacing any previous binding.
+#[reducer]
+pub fn identity_sign_in(ctx: &ReducerContext, identity: Identity, fid: i64) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend"])?;
+    bind_identity(ctx, identity, fid, "sign_in");
+    append_event(ctx, "identity_linked", fid, format!("{{\"identity\":\"{}\"}}", identity), None);
+    Ok(())
+}
+
+/// Drops the caller's identity binding (sign-out).
+#[reducer]
+pub fn identity_unlink(ctx: &ReducerContext) -> Result<(), ReducerError> {
+    ctx.db().identity_link().identity().delete(ctx.sender);
+    Ok(())
+}
  
- #[reducer]
- pub fn grant_starter_pack(ctx: &ReducerContext, fid: i64, players_json: String) {
-    // Parse and validate payload FIRST to avoid partial writes on bad input
-    let payload: StarterPackPayload = match serde_json::from_str(&players_json) {
-        Ok(p) => p,
-        Err(_) => panic!("invalid_payload"),
-    };
-    if payload.players.is_empty() { panic!("no_players"); }
+/// Picks an unused `player-{uuid}` id. Ids derive from the fid, seed and slot,
+/// and a taken id (however unlikely) is re-hashed rather than overwritten.
+fn unique_player_id(ctx: &ReducerContext, fid: i64, seed: u64, slot: usize) -> String {
+    let items = ctx.db().inventory_item();
+    let mut attempt = 0u32;
+    loop {
+        let id = format!("player-{}", new_id(ctx, "player", &format!("{}:{}:{}:{}", fid, seed, slot, attempt)));
+        if items.item_id().find(&id).is_none() { return id; }
+        attempt += 1;
+    }
+}
 
-    if ctx.db().starter_claim().fid().find(&fid).is_some() { panic!("starter_already_claimed"); }
+ #[reducer]
+pub fn grant_starter_pack(ctx: &ReducerContext, fid: i64) -> Result<(), ReducerError> {
+    // Granted by the backend once the on-chain payment is verified
+    require_role(ctx, &["backend"])?;
+    if ctx.db().starter_claim().fid().find(fid).is_some() { return Err(ReducerError::StarterAlreadyClaimed); }
 
     let now = now_ms(ctx);
-   // Record the claim only after validation
     ctx.db().starter_claim().insert(StarterClaim { fid, claimed_at_ms: now });
 
-    // Store a canonical JSON form of the payload in the event for reliable consumers
-    let evt_payload = serde_json::to_string(&payload).unwrap_or_else(|_| players_json.clone());
-    let evt = append_event(ctx, "starter_pack_granted", fid, evt_payload, None);
+    // The module rolls the squad itself; the seed is kept so the pack can be reproduced
+    let seed = sim::seed_for(&fid.to_string(), ctx.rng().gen());
+    let cards: Vec<PlayerCard> = starter::generate(seed)
+        .into_iter()
+        .enumerate()
+        .map(|(slot, p)| PlayerCard {
+            item_id: unique_player_id(ctx, fid, seed, slot),
+            name: p.name,
+            positions: p.positions.iter().map(|s| s.to_string()).collect(),
+            overall: p.rating,
+            pace: p.attributes.pace,
+            shooting: p.attributes.shooting,
+            passing: p.attributes.passing,
+            defending: p.attributes.defending,
+            physical: p.attributes.physical,
+            goalkeeping: p.attributes.goalkeeping,
+            preferred_foot: p.preferred_foot.into(),
+            potential: p.potential,
+            created_at_ms: now,
+        })
+        .collect();
+    let players = cards.iter()
+        .map(|c| StarterPlayer { player_id: c.item_id.clone(), name: Some(c.name.clone()), position: c.positions.first().cloned(), rating: Some(c.overall) })
+        .collect();
+    let payload = StarterPackPayload { players, seed: seed.to_string() };
+    let evt = append_event(ctx, "starter_pack_granted", fid, serde_json::to_string(&payload).unwrap_or("{}".into()), None);
 
     let hold_until = now + HOLD_DAYS * 24 * 60 * 60 * 1000;
-    for p in payload.players.iter() {
-        let items = ctx.db().inventory_item();
-        if items.item_id().find(&p.player_id).is_some() { items.item_id().delete(&p.player_id); }
-        items.insert(InventoryItem { item_id: p.player_id.clone(), owner_fid: fid, item_type: "player".into(), acquired_at_ms: now, hold_until_ms: hold_until, source_event_id: evt.id.clone() });
+    for card in cards {
+        ctx.db().inventory_item().insert(InventoryItem { item_id: card.item_id.clone(), owner_fid: fid, item_type: "player".into(), acquired_at_ms: now, hold_until_ms: hold_until, source_event_id: evt.id.clone() });
+        ctx.db().player_card().insert(card);
     }
     push_inbox(ctx, fid, format!("starter-{}", evt.id), "starter_pack", "Starter Pack Granted", &format!("You received {} players from starter pack.", payload.players.len()));
- }
- 
+    Ok(())
+}
+
  #[reducer]
- pub fn create_listing(ctx: &ReducerContext, fid: i64, item_id: String, price_wei: String) {
-     let item = ctx.db().inventory_item().item_id().find(&item_id).ok_or("item_not_found").unwrap();
-     if item.owner_fid != fid { panic!("not_owner"); }
-     if now_ms(ctx) < item.hold_until_ms && fid != DEV_FID { panic!("in_hold"); }
+ pub fn create_listing(ctx: &ReducerContext, fid: i64, item_id: String, price_wei: String) -> Result<(), ReducerError> {
+     let fid = resolve_actor(ctx, fid)?;
+     let item = ctx.db().inventory_item().item_id().find(&item_id).ok_or(ReducerError::ItemNotFound)?;
+     if item.owner_fid != fid { return Err(ReducerError::NotOwner); }
+    check_hold(item.hold_until_ms, now_ms(ctx), is_hold_exempt(ctx, fid))?;
+     let price_wei = wei::parse_positive(&price_wei)?;
+    check_min_price(&load_market_limits(ctx, &item.item_type), price_wei)?;
      let id = new_id(ctx, "lst", &format!("{}:{}", fid, item_id));
-     let listing = Listing { id: id.clone(), item_id, seller_fid: fid, price_wei, status: "active".into(), created_at_ms: now_ms(ctx), closed_at_ms: None };
+    lock_item(ctx, &item_id, "listing", &id, fid)?;
+     let listing = Listing { id: id.clone(), item_id, seller_fid: fid, price_wei, status: "active".into(), created_at_ms: now_ms(ctx), closed_at_ms: None, pending_buyer_fid: None, pending_until_ms: None };
      ctx.db().listing().insert(listing);
+     Ok(())
  }
  
  #[reducer]
- pub fn close_listing_and_transfer(ctx: &ReducerContext, listing_id: String, buyer_fid: i64) {
+ pub fn close_listing_and_transfer(ctx: &ReducerContext, listing_id: String, buyer_fid: i64) -> Result<(), ReducerError> {
+     let buyer_fid = resolve_actor(ctx, buyer_fid)?;
      let listings = ctx.db().listing();
-     let mut l = listings.id().find(&listing_id).ok_or("listing_not_found").unwrap();
-     if l.status != "active" { panic!("listing_closed"); }
+     let mut l = listings.id().find(&listing_id).ok_or(ReducerError::ListingNotFound)?;
+     if l.status != "active" { return Err(ReducerError::ListingClosed); }
+     check_purchase_window(ctx, &l, buyer_fid)?;
      l.status = "closed".into();
      l.closed_at_ms = Some(now_ms(ctx));
      listings.id().update(l.clone());
      let evt = append_event(ctx, "ListingSold", buyer_fid, serde_json::to_string(&l).unwrap_or("{}".into()), Some(listing_id.clone()));
-    transfer_item(ctx, &l.item_id, l.seller_fid, buyer_fid, &evt.id).unwrap();
+     settle_payment(ctx, buyer_fid, l.seller_fid, l.price_wei, &listing_id, false)?;
+    transfer_item(ctx, &l.item_id, l.seller_fid, buyer_fid, &evt.id, &l.id)?;
     on_item_transferred(ctx, &l.item_id, buyer_fid);
      push_inbox(ctx, l.seller_fid, format!("listing-sold-{}", evt.id), "listing_sold", "Item Sold!", "Your item was purchased.");
      push_inbox(ctx, buyer_fid, format!("listing-bought-{}", evt.id), "listing_bought", "Purchase Complete", "You bought an item.");
+     Ok(())
  }
  
- #[reducer]
- pub fn create_auction(ctx: &ReducerContext, fid: i64, item_id: String, reserve_wei: String, duration_seconds: i64, buy_now_wei: Option<String>) {
-     let item = ctx.db().inventory_item().item_id().find(&item_id).ok_or("item_not_found").unwrap();
-     if item.owner_fid != fid { panic!("not_owner"); }
-     if now_ms(ctx) < item.hold_until_ms && fid != DEV_FID { panic!("in_hold"); }
+/// Loads an active listing for a seller-side change, refusing while a buyer's
+/// purchase window is open.
+fn seller_editable_listing(ctx: &ReducerContext, fid: i64, listing_id: &str) -> Result<Listing, ReducerError> {
+    let l = ctx.db().listing().id().find(listing_id.to_string()).ok_or(ReducerError::ListingNotFound)?;
+    if l.seller_fid != fid { return Err(ReducerError::NotOwner); }
+    if l.status != "active" { return Err(ReducerError::ListingClosed); }
+    if l.pending_until_ms.is_some_and(|until| now_ms(ctx) < until) { return Err(ReducerError::PurchasePending); }
+    Ok(l)
+}
+
+/// While a purchase window is open only the buyer who reserved it may buy.
+fn check_purchase_window(ctx: &ReducerContext, l: &Listing, buyer_fid: i64) -> Result<(), ReducerError> {
+    let open = l.pending_until_ms.is_some_and(|until| now_ms(ctx) < until);
+    if open && l.pending_buyer_fid != Some(buyer_fid) { return Err(ReducerError::PurchasePending); }
+    Ok(())
+}
+
+#[reducer]
+pub fn cancel_listing(ctx: &ReducerContext, fid: i64, listing_id: String) -> Result<(), ReducerError> {
+    let fid = resolve_actor(ctx, fid)?;
+    let mut l = seller_editable_listing(ctx, fid, &listing_id)?;
+    l.status = "cancelled".into();
+    l.closed_at_ms = Some(now_ms(ctx));
+    ctx.db().listing().id().update(l.clone());
+    release_item_lock(ctx, &l.item_id, &l.id);
+    append_event(ctx, "ListingCancelled", fid, serde_json::to_string(&l).unwrap_or("{}".into()), Some(listing_id));
+    Ok(())
+}
+
+#[reducer]
+pub fn update_listing_price(ctx: &ReducerContext, fid: i64, listing_id: String, price_wei: String) -> Result<(), ReducerError> {
+    let fid = resolve_actor(ctx, fid)?;
+    let mut l = seller_editable_listing(ctx, fid, &listing_id)?;
+    let new_price = wei::parse_positive(&price_wei)?;
+    let item_type = ctx.db().inventory_item().item_id().find(&l.item_id).map(|i| i.item_type).unwrap_or_default();
+    check_min_price(&load_market_limits(ctx, &item_type), new_price)?;
+    let old_price = l.price_wei;
+    if new_price == old_price { return Ok(()); }
+    l.price_wei = new_price;
+    ctx.db().listing().id().update(l);
+    // Price history is read back from these events
+    let payload = serde_json::json!({ "old_price_wei": old_price.to_string(), "new_price_wei": new_price.to_string() }).to_string();
+    append_event(ctx, "ListingPriceUpdated", fid, payload, Some(listing_id));
+    Ok(())
+}
+
+/// A buyer announces they are about to pay on-chain; the price is frozen for
+/// `LISTING_PURCHASE_WINDOW_SECONDS` so it cannot change under their transfer.
+/// Each buyer gets one window per listing, so a reservation cannot be held open.
+#[reducer]
+pub fn listing_reserve_purchase(ctx: &ReducerContext, listing_id: String, buyer_fid: i64) -> Result<(), ReducerError> {
+    let buyer_fid = resolve_actor(ctx, buyer_fid)?;
+    let listings = ctx.db().listing();
+    let mut l = listings.id().find(&listing_id).ok_or(ReducerError::ListingNotFound)?;
+    if l.status != "active" { return Err(ReducerError::ListingClosed); }
+    if l.seller_fid == buyer_fid { return Err(ReducerError::SameFid); }
+    let now = now_ms(ctx);
+    if l.pending_until_ms.is_some_and(|until| now < until) { return Err(ReducerError::PurchasePending); }
+    let reservations = ctx.db().listing_reservation();
+    let reservation_id = format!("{}:{}", listing_id, buyer_fid);
+    if reservations.id().find(&reservation_id).is_some() { return Err(ReducerError::ReservationUsed); }
+    reservations.insert(ListingReservation { id: reservation_id, listing_id: listing_id.clone(), buyer_fid, reserved_at_ms: now });
+    l.pending_buyer_fid = Some(buyer_fid);
+    l.pending_until_ms = Some(now + LISTING_PURCHASE_WINDOW_SECONDS * 1000);
+    listings.id().update(l);
+    append_event(ctx, "ListingPurchaseReserved", buyer_fid, "{}".into(), Some(listing_id));
+    Ok(())
+}
+
+fn load_market_limits(ctx: &ReducerContext, item_type: &str) -> MarketLimits {
+    ctx.db().market_limits().item_type().find(item_type.to_string()).unwrap_or_else(|| {
+        let (min_auction_seconds, max_auction_seconds) = match item_type {
+            "squad" => (SQUAD_AUCTION_MIN_SECONDS, SQUAD_AUCTION_MAX_SECONDS),
+            _ => (AUCTION_MIN_SECONDS, AUCTION_MAX_SECONDS),
+        };
+        MarketLimits { item_type: item_type.to_string(), min_auction_seconds, max_auction_seconds, min_price_wei: u256::new(MIN_LISTING_PRICE_WEI), updated_at_ms: 0 }
+    })
+}
+
+fn check_min_price(limits: &MarketLimits, price: u256) -> Result<(), ReducerError> {
+    if price < limits.min_price_wei { Err(ReducerError::PriceBelowMinimum) } else { Ok(()) }
+}
+
+/// Parses and checks create_auction's terms against the item type's limits.
+/// Returns the (reserve, buy-now) amounts.
+fn validate_auction_terms(limits: &MarketLimits, reserve_wei: &str, duration_seconds: i64, buy_now_wei: Option<&str>) -> Result<(u256, Option<u256>), ReducerError> {
+    if duration_seconds < limits.min_auction_seconds { return Err(ReducerError::AuctionTooShort); }
+    if duration_seconds > limits.max_auction_seconds { return Err(ReducerError::AuctionTooLong); }
+    // A zero reserve means "no reserve"
+    let reserve = wei::parse(reserve_wei).map_err(|_| ReducerError::InvalidReserve)?;
+    if reserve > u256::ZERO { check_min_price(limits, reserve)?; }
+    let buy_now = wei::parse_opt(buy_now_wei).map_err(|_| ReducerError::InvalidBuyNow)?;
+    if let Some(buy_now) = buy_now {
+        check_min_price(limits, buy_now)?;
+        if buy_now < reserve { return Err(ReducerError::BuyNowBelowReserve); }
+    }
+    Ok((reserve, buy_now))
+}
+
+#[reducer]
+pub fn market_limits_set(ctx: &ReducerContext, item_type: String, min_auction_seconds: i64, max_auction_seconds: i64, min_price_wei: String) -> Result<(), ReducerError> {
+    require_role(ctx, &["admin"])?;
+    if !matches!(item_type.as_str(), "player" | "squad" | "npc_manager") { return Err(ReducerError::InvalidMarketLimits); }
+    let bounds = 60..=30 * 24 * 60 * 60;
+    if !bounds.contains(&min_auction_seconds) || !bounds.contains(&max_auction_seconds) || min_auction_seconds > max_auction_seconds { return Err(ReducerError::InvalidMarketLimits); }
+    // A malformed amount is still invalid_amount; a zero floor is a bad limit
+    let min_price_wei = wei::parse(&min_price_wei)?;
+    if min_price_wei == u256::ZERO { return Err(ReducerError::InvalidMarketLimits); }
+    let limits = MarketLimits { item_type: item_type.clone(), min_auction_seconds, max_auction_seconds, min_price_wei, updated_at_ms: now_ms(ctx) };
+    let tbl = ctx.db().market_limits();
+    if tbl.item_type().find(&item_type).is_some() { tbl.item_type().update(limits); } else { tbl.insert(limits); }
+    Ok(())
+}
+
+fn parse_anti_snipe(json: Option<&str>) -> Result<AntiSnipePolicy, ReducerError> {
+    let policy: AntiSnipePolicy = match json.map(str::trim).filter(|j| !j.is_empty()) {
+        Some(j) => serde_json::from_str(j).map_err(|_| ReducerError::InvalidJson)?,
+        None => AntiSnipePolicy::default(),
+    };
+    let hour = 60 * 60;
+    if !(0..=hour).contains(&policy.window_seconds) || !(0..=hour).contains(&policy.extend_seconds) { return Err(ReducerError::InvalidAntiSnipe); }
+    if !(0..=100).contains(&policy.max_extensions) { return Err(ReducerError::InvalidAntiSnipe); }
+    if policy.max_total_extension_seconds.is_some_and(|cap| !(0..=7 * 24 * hour).contains(&cap)) { return Err(ReducerError::InvalidAntiSnipe); }
+    Ok(policy)
+}
+
+/// End time after a bid at `now`, or `None` if the policy does not extend.
+fn anti_snipe_extension(a: &Auction, now: i64) -> Option<i64> {
+    if a.anti_snipe_extend_seconds == 0 || a.anti_snipe_extensions >= a.anti_snipe_max_extensions { return None; }
+    if a.ends_at_ms - now > a.anti_snipe_window_seconds * 1000 { return None; }
+    let extended = a.ends_at_ms + a.anti_snipe_extend_seconds * 1000;
+    let capped = a.max_ends_at_ms.map_or(extended, |cap| extended.min(cap));
+    (capped > a.ends_at_ms).then_some(capped)
+}
+
+ #[reducer]
+ pub fn create_auction(ctx: &ReducerContext, fid: i64, item_id: String, reserve_wei: String, duration_seconds: i64, buy_now_wei: Option<String>, anti_snipe_json: Option<String>) -> Result<(), ReducerError> {
+     let fid = resolve_actor(ctx, fid)?;
+     let item = ctx.db().inventory_item().item_id().find(&item_id).ok_or(ReducerError::ItemNotFound)?;
+     if item.owner_fid != fid { return Err(ReducerError::NotOwner); }
+    check_hold(item.hold_until_ms, now_ms(ctx), is_hold_exempt(ctx, fid))?;
+    let limits = load_market_limits(ctx, &item.item_type);
+    let (reserve_wei, buy_now_wei) = validate_auction_terms(&limits, &reserve_wei, duration_seconds, buy_now_wei.as_deref())?;
+    let policy = parse_anti_snipe(anti_snipe_json.as_deref())?;
      let id = new_id(ctx, "auc", &format!("{}:{}", fid, item_id));
      let ends = now_ms(ctx) + duration_seconds * 1000;
-     let a = Auction { id: id.clone(), item_id, seller_fid: fid, reserve_wei, ends_at_ms: ends, status: "active".into(), top_bid_wei: None, top_bidder_fid: None, buy_now_wei, anti_snipe_used: false, created_at_ms: now_ms(ctx), finalized_at_ms: None };
+    lock_item(ctx, &item_id, "auction", &id, fid)?;
+     let a = Auction {
+        id: id.clone(), item_id, seller_fid: fid, reserve_wei, ends_at_ms: ends, status: "active".into(), top_bid_wei: None, top_bidder_fid: None, buy_now_wei, anti_snipe_used: false, created_at_ms: now_ms(ctx), finalized_at_ms: None,
+        anti_snipe_window_seconds: policy.window_seconds,
+        anti_snipe_extend_seconds: policy.extend_seconds,
+        anti_snipe_max_extensions: policy.max_extensions,
+        anti_snipe_extensions: 0,
+        max_ends_at_ms: policy.max_total_extension_seconds.map(|cap| ends + cap * 1000),
+    };
      ctx.db().auction().insert(a);
+     schedule_auction_close(ctx, &id, ends);
+     Ok(())
  }
  
  #[reducer]
- pub fn place_bid(ctx: &ReducerContext, fid: i64, auction_id: String, amount_wei: String) {
+ pub fn place_bid(ctx: &ReducerContext, fid: i64, auction_id: String, amount_wei: String) -> Result<(), ReducerError> {
+     let fid = resolve_actor(ctx, fid)?;
      let auctions = ctx.db().auction();
-     let mut a = auctions.id().find(&auction_id).ok_or("auction_not_found").unwrap();
-     if a.status != "active" { panic!("auction_closed"); }
-     if now_ms(ctx) > a.ends_at_ms { panic!("auction_ended"); }
-     let current = parse_wei(a.top_bid_wei.as_deref());
-     let next = parse_wei(Some(&amount_wei));
-     if current == 0 {
-         let reserve = parse_wei(Some(&a.reserve_wei));
-         if next < reserve { panic!("below_reserve"); }
-     } else {
-         let min_inc = ((current as f64) * 1.02).round() as i128;
-         if next < min_inc { panic!("below_increment"); }
-     }
-     let bid_id = new_id(ctx, "bid", &format!("{}:{}:{}", fid, auction_id, amount_wei));
-     ctx.db().bid().insert(Bid { id: bid_id, auction_id: auction_id.clone(), fid, amount_wei: amount_wei.clone(), placed_at_ms: now_ms(ctx) });
-     let mut anti = false;
-     let mut new_ends = a.ends_at_ms;
-     if !a.anti_snipe_used && a.ends_at_ms - now_ms(ctx) <= ANTI_SNIPE_EXTEND_SECONDS * 1000 {
-         anti = true; new_ends = a.ends_at_ms + ANTI_SNIPE_EXTEND_SECONDS * 1000;
-     }
-     a.top_bid_wei = Some(amount_wei);
-     a.top_bidder_fid = Some(fid);
-     a.anti_snipe_used = a.anti_snipe_used || anti;
-     a.ends_at_ms = new_ends;
+     let mut a = auctions.id().find(&auction_id).ok_or(ReducerError::AuctionNotFound)?;
+     if a.status != "active" { return Err(ReducerError::AuctionClosed); }
+     if a.seller_fid == fid { return Err(ReducerError::SameFid); }
+     if now_ms(ctx) > a.ends_at_ms { return Err(ReducerError::AuctionEnded); }
+     let next = wei::parse_positive(&amount_wei)?;
+    // The reserve is the minimum sale price, not a minimum bid: lower bids are
+    // accepted and the auction closes as "reserve_not_met"
+    if let Some(current) = a.top_bid_wei {
+        if next < wei::min_next_bid(current)? { return Err(ReducerError::BelowIncrement); }
+    }
+    apply_bid(ctx, &mut a, fid, next)?;
+    // Registered max bids answer the new top bid
+    resolve_proxy_bids(ctx, &mut a)?;
      auctions.id().update(a);
+     Ok(())
  }
+
+/// Makes `fid` the top bidder at `amount`: escrows the funds, records the Bid
+/// row and applies anti-snipe. The caller writes the auction back.
+fn apply_bid(ctx: &ReducerContext, a: &mut Auction, fid: i64, amount: u256) -> Result<(), ReducerError> {
+     // Outbid funds go back first so a bidder raising their own bid can reuse them
+     refund_top_bid(ctx, a)?;
+     escrow_lock(ctx, fid, amount, &a.id)?;
+     if let Some(prev) = a.top_bidder_fid.filter(|p| *p != fid) {
+         push_inbox(ctx, prev, format!("outbid-{}-{}", a.id, amount), "auction_outbid", "You Were Outbid", "Your bid was beaten and your funds were returned.");
+     }
+    record_bid(ctx, &a.id, fid, amount);
+    if let Some(new_ends) = anti_snipe_extension(a, now_ms(ctx)) {
+        a.ends_at_ms = new_ends;
+        a.anti_snipe_extensions += 1;
+        a.anti_snipe_used = true;
+    }
+     a.top_bid_wei = Some(amount);
+     a.top_bidder_fid = Some(fid);
+    Ok(())
+}
+
+fn record_bid(ctx: &ReducerContext, auction_id: &str, fid: i64, amount: u256) {
+    let bid_id = new_id(ctx, "bid", &format!("{}:{}:{}", fid, auction_id, amount));
+    ctx.db().bid().insert(Bid { id: bid_id, auction_id: auction_id.to_string(), fid, amount_wei: amount, placed_at_ms: now_ms(ctx) });
+}
+
+/// What `fid` can put behind a bid on `a`: their available balance plus the
+/// escrow they already hold as top bidder.
+fn spendable_on(ctx: &ReducerContext, a: &Auction, fid: i64) -> Result<u256, ReducerError> {
+    let held = if a.top_bidder_fid == Some(fid) { a.top_bid_wei.unwrap_or(u256::ZERO) } else { u256::ZERO };
+    wei::add(load_balance(ctx, fid).available_wei, held)
+}
+
+/// Runs registered max bids against the current top bid in one pass. The
+/// highest cap (earliest on ties) wins at one increment above the strongest
+/// competitor, or at its cap if that is lower. A beaten proxy is recorded as a
+/// Bid at its cap, so the history shows the war without revealing the winner's cap.
+fn resolve_proxy_bids(ctx: &ReducerContext, a: &mut Auction) -> Result<(), ReducerError> {
+    // Caps are limited to what each bidder can currently fund
+    let mut proxies: Vec<(u256, i64, i64, u64)> = Vec::new();
+    for p in ctx.db().proxy_bid().auction_id().filter(&a.id) {
+        let cap = p.max_wei.min(spendable_on(ctx, a, p.fid)?);
+        if cap > u256::ZERO { proxies.push((cap, p.placed_at_ms, p.fid, p.id)); }
+    }
+    proxies.sort_by(|x, y| y.0.cmp(&x.0).then(x.1.cmp(&y.1)).then(x.3.cmp(&y.3)));
+    let Some(&(leader_cap, _, leader_fid, _)) = proxies.first() else { return Ok(()); };
+
+    // Lowest amount any new bid must reach
+    let need = match a.top_bid_wei {
+        Some(top) => wei::min_next_bid(top)?,
+        None => u256::ONE,
+    };
+    let rival = proxies.iter().find(|p| p.2 != leader_fid && p.0 >= need).map(|p| (p.0, p.2));
+    let leading = a.top_bidder_fid == Some(leader_fid);
+    if leading && rival.is_none() { return Ok(()); }
+    if !leading && leader_cap < need { return Ok(()); }
+
+    // Strongest competing amount: a beaten proxy's cap, else the standing bid
+    let beaten = match (rival, a.top_bid_wei) {
+        (Some((cap, _)), _) => Some(cap),
+        (None, top) => top,
+    };
+    let price = match beaten {
+        Some(amount) => leader_cap.min(wei::min_next_bid(amount)?.max(need)),
+        // Opening bid: up to the reserve, which stays hidden until it is met
+        None => leader_cap.min(a.reserve_wei.max(u256::ONE)),
+    };
+    if let Some((cap, fid)) = rival {
+        record_bid(ctx, &a.id, fid, cap);
+        if a.top_bidder_fid != Some(fid) {
+            push_inbox(ctx, fid, format!("outbid-{}-{}", a.id, cap), "auction_outbid", "You Were Outbid", "Your maximum bid was beaten.");
+        }
+    }
+    apply_bid(ctx, a, leader_fid, price)
+}
+
+/// Registers (or replaces) the caller's maximum bid. The module bids on their
+/// behalf, one increment at a time, up to `max_wei`.
+#[reducer]
+pub fn set_max_bid(ctx: &ReducerContext, fid: i64, auction_id: String, max_wei: String) -> Result<(), ReducerError> {
+    let fid = resolve_actor(ctx, fid)?;
+    let auctions = ctx.db().auction();
+    let mut a = auctions.id().find(&auction_id).ok_or(ReducerError::AuctionNotFound)?;
+    if a.status != "active" { return Err(ReducerError::AuctionClosed); }
+    if now_ms(ctx) > a.ends_at_ms { return Err(ReducerError::AuctionEnded); }
+    if a.seller_fid == fid { return Err(ReducerError::SameFid); }
+    let max = wei::parse_positive(&max_wei)?;
+    // The top bidder may register a cap at their current bid; others must beat it
+    let floor = match a.top_bid_wei {
+        Some(top) if a.top_bidder_fid == Some(fid) => top,
+        Some(top) => wei::min_next_bid(top)?,
+        None => u256::ONE,
+    };
+    if max < floor { return Err(ReducerError::BelowIncrement); }
+    if max > spendable_on(ctx, &a, fid)? { return Err(ReducerError::InsufficientFunds); }
+    let proxies = ctx.db().proxy_bid();
+    let previous: Vec<ProxyBid> = proxies.auction_id().filter(&auction_id).filter(|p| p.fid == fid).collect();
+    for p in previous { proxies.id().delete(p.id); }
+    proxies.insert(ProxyBid { id: 0, auction_id, fid, max_wei: max, placed_at_ms: now_ms(ctx) });
+    resolve_proxy_bids(ctx, &mut a)?;
+    auctions.id().update(a);
+    Ok(())
+}
+
+fn clear_proxy_bids(ctx: &ReducerContext, auction_id: &str) {
+    let proxies = ctx.db().proxy_bid();
+    let ids: Vec<u64> = proxies.auction_id().filter(auction_id).map(|p| p.id).collect();
+    for id in ids { proxies.id().delete(id); }
+}
  
  #[reducer]
- pub fn buy_now(ctx: &ReducerContext, auction_id: String, buyer_fid: i64, buy_now_wei: String) {
+ pub fn buy_now(ctx: &ReducerContext, auction_id: String, buyer_fid: i64, buy_now_wei: String) -> Result<(), ReducerError> {
+     let buyer_fid = resolve_actor(ctx, buyer_fid)?;
      let auctions = ctx.db().auction();
-     let mut a = auctions.id().find(&auction_id).ok_or("auction_not_found").unwrap();
-     if a.status != "active" { panic!("auction_closed"); }
-     if a.buy_now_wei.as_deref() != Some(&buy_now_wei) { panic!("invalid_buy_now"); }
+     let mut a = auctions.id().find(&auction_id).ok_or(ReducerError::AuctionNotFound)?;
+     if a.status != "active" { return Err(ReducerError::AuctionClosed); }
+    if a.seller_fid == buyer_fid { return Err(ReducerError::SameFid); }
+    if now_ms(ctx) >= a.ends_at_ms { return Err(ReducerError::AuctionEnded); }
+     let buy_now_wei = wei::parse_positive(&buy_now_wei)?;
+     if a.buy_now_wei != Some(buy_now_wei) { return Err(ReducerError::InvalidBuyNow); }
+     refund_top_bid(ctx, &a)?;
+     settle_payment(ctx, buyer_fid, a.seller_fid, buy_now_wei, &auction_id, false)?;
      let evt = append_event(ctx, "AuctionBuyNow", buyer_fid, serde_json::to_string(&a).unwrap_or("{}".into()), Some(auction_id));
      a.status = "finalized".into();
      a.finalized_at_ms = Some(now_ms(ctx));
      a.top_bid_wei = Some(buy_now_wei);
      a.top_bidder_fid = Some(buyer_fid);
      auctions.id().update(a.clone());
-    transfer_item(ctx, &a.item_id, a.seller_fid, buyer_fid, &evt.id).unwrap();
+    clear_proxy_bids(ctx, &a.id);
+    transfer_item(ctx, &a.item_id, a.seller_fid, buyer_fid, &evt.id, &a.id)?;
     on_item_transferred(ctx, &a.item_id, buyer_fid);
+     Ok(())
  }
  
  #[reducer]
- pub fn finalize_auction(ctx: &ReducerContext, auction_id: String, winner_fid: i64) {
+ pub fn finalize_auction(ctx: &ReducerContext, auction_id: String, winner_fid: i64) -> Result<(), ReducerError> {
      let auctions = ctx.db().auction();
-     let mut a = auctions.id().find(&auction_id).ok_or("auction_not_found").unwrap();
-     if a.status != "active" { panic!("auction_closed"); }
-     if a.top_bidder_fid != Some(winner_fid) { panic!("not_winner"); }
-     let evt = append_event(ctx, "AuctionFinalized", winner_fid, serde_json::to_string(&a).unwrap_or("{}".into()), Some(auction_id));
-     a.status = "finalized".into();
-     a.finalized_at_ms = Some(now_ms(ctx));
-     auctions.id().update(a.clone());
-    transfer_item(ctx, &a.item_id, a.seller_fid, winner_fid, &evt.id).unwrap();
-    on_item_transferred(ctx, &a.item_id, winner_fid);
+     let a = auctions.id().find(&auction_id).ok_or(ReducerError::AuctionNotFound)?;
+     if a.status != "active" { return Err(ReducerError::AuctionClosed); }
+     if now_ms(ctx) < a.ends_at_ms { return Err(ReducerError::AuctionNotEnded); }
+     if a.top_bidder_fid != Some(winner_fid) { return Err(ReducerError::NotWinner); }
+     // Either party (or the backend) may settle the auction
+     if !is_trusted_service(ctx) {
+         let caller = linked_fid(ctx).ok_or(ReducerError::IdentityNotLinked)?;
+         if caller != winner_fid && caller != a.seller_fid { return Err(ReducerError::NotParticipant); }
+     }
+    close_ended_auction(ctx, a)
  }
+
+/// Sellers may cancel freely before the first bid. Afterwards the top bid is
+/// refunded and the seller pays the top bidder `AUCTION_CANCEL_PENALTY_BPS`.
+#[reducer]
+pub fn cancel_auction(ctx: &ReducerContext, fid: i64, auction_id: String) -> Result<(), ReducerError> {
+    let fid = resolve_actor(ctx, fid)?;
+    let a = ctx.db().auction().id().find(&auction_id).ok_or(ReducerError::AuctionNotFound)?;
+    if a.seller_fid != fid { return Err(ReducerError::NotOwner); }
+    if a.status != "active" { return Err(ReducerError::AuctionClosed); }
+    if now_ms(ctx) >= a.ends_at_ms { return Err(ReducerError::AuctionEnded); }
+    if let (Some(bidder), Some(top)) = (a.top_bidder_fid, a.top_bid_wei) {
+        let bps = u256::new(AUCTION_CANCEL_PENALTY_BPS as u128);
+        let penalty = top.checked_mul(bps).ok_or(ReducerError::AmountOverflow)? / u256::new(10_000);
+        if penalty > u256::ZERO {
+            settle_payment(ctx, fid, bidder, penalty, &format!("{}:cancel_penalty", a.id), false)?;
+        }
+    }
+    close_unsold_auction(ctx, a, "cancelled")
+}
+
+/// Scheduled: settles an auction once its (possibly extended) end time passes.
+#[reducer]
+pub fn auction_close_tick(ctx: &ReducerContext, timer: AuctionTimer) -> Result<(), ReducerError> {
+    if ctx.sender != ctx.identity() { return Err(ReducerError::SchedulerOnly); }
+    let Some(a) = ctx.db().auction().id().find(&timer.auction_id) else { return Ok(()); };
+    // Already settled via buy-now or a manual finalize
+    if a.status != "active" { return Ok(()); }
+    if now_ms(ctx) < a.ends_at_ms {
+        schedule_auction_close(ctx, &a.id, a.ends_at_ms);
+        return Ok(());
+    }
+    close_ended_auction(ctx, a)
+}
  
  #[reducer]
- pub fn inbox_mark_read(ctx: &ReducerContext, fid: i64, msg_ids_json: String) {
+ pub fn inbox_mark_read(ctx: &ReducerContext, fid: i64, msg_ids_json: String) -> Result<(), ReducerError> {
+     let fid = resolve_actor(ctx, fid)?;
      let ids: Vec<String> = serde_json::from_str(&msg_ids_json).unwrap_or_default();
      for id in ids.iter() {
          let inbox_tbl = ctx.db().inbox();
          if let Some(mut m) = inbox_tbl.msg_id().find(id) { if m.fid == fid { m.read_at_ms = Some(now_ms(ctx)); inbox_tbl.msg_id().update(m); } }
      }
+     Ok(())
  }
  
  #[reducer]
- pub fn pvp_create_challenge(ctx: &ReducerContext, challenger_fid: i64, challenged_fid: i64) {
-     if challenger_fid == challenged_fid { panic!("same_fid"); }
-     if have_pending_pvp_between(ctx, challenger_fid, challenged_fid) { panic!("duplicate_pending"); }
+ pub fn pvp_create_challenge(ctx: &ReducerContext, challenger_fid: i64, challenged_fid: i64) -> Result<(), ReducerError> {
+     let challenger_fid = resolve_actor(ctx, challenger_fid)?;
+     if challenger_fid == challenged_fid { return Err(ReducerError::SameFid); }
+     if have_pending_pvp_between(ctx, challenger_fid, challenged_fid) { return Err(ReducerError::DuplicatePending); }
      let id = new_id(ctx, "pvp", &format!("{}:{}", challenger_fid, challenged_fid));
-     let m = PvpMatch { id: id.clone(), challenger_fid, challenged_fid, status: "pending".into(), created_at_ms: now_ms(ctx), accepted_at_ms: None, result_json: None };
+     let m = PvpMatch { id: id.clone(), challenger_fid, challenged_fid, status: "pending".into(), created_at_ms: now_ms(ctx), accepted_at_ms: None, result_json: None, reported_by_fid: None, reported_at_ms: None, finalized_at_ms: None };
      ctx.db().pvp_match().insert(m);
+    schedule_pvp_timeout(ctx, &id, "expire", load_pvp_config(ctx).challenge_expiry_hours);
      append_event(ctx, "pvp_match_created", challenger_fid, "{}".into(), Some(id.clone()));
      push_inbox(ctx, challenged_fid, format!("pvp-challenge-{}", id), "pvp_challenge", "New Challenge", &format!("FID {} challenged you.", challenger_fid));
+     Ok(())
  }
  
  #[reducer]
- pub fn pvp_accept(ctx: &ReducerContext, match_id: String, accepter_fid: i64) {
+ pub fn pvp_accept(ctx: &ReducerContext, match_id: String, accepter_fid: i64) -> Result<(), ReducerError> {
+     let accepter_fid = resolve_actor(ctx, accepter_fid)?;
      let tbl = ctx.db().pvp_match();
-     let mut m = tbl.id().find(&match_id).ok_or("match_not_found").unwrap();
-     if m.status != "pending" { panic!("invalid_state"); }
-     if m.challenged_fid != accepter_fid { panic!("not_challenged"); }
+     let mut m = tbl.id().find(&match_id).ok_or(ReducerError::MatchNotFound)?;
+     if m.status != "pending" { return Err(ReducerError::InvalidState); }
+     if m.challenged_fid != accepter_fid { return Err(ReducerError::NotChallenged); }
      m.status = "active".into();
      m.accepted_at_ms = Some(now_ms(ctx));
      tbl.id().update(m.clone());
+    schedule_pvp_timeout(ctx, &match_id, "abandon", load_pvp_config(ctx).active_deadline_hours);
      append_event(ctx, "pvp_match_accepted", accepter_fid, "{}".into(), Some(match_id));
+     Ok(())
  }
+
+fn load_pvp_config(ctx: &ReducerContext) -> PvpConfig {
+    ctx.db().pvp_config().id().find(0).unwrap_or(PvpConfig {
+        id: 0,
+        challenge_expiry_hours: PVP_CHALLENGE_EXPIRY_HOURS,
+        active_deadline_hours: PVP_ACTIVE_DEADLINE_HOURS,
+        updated_at_ms: 0,
+    })
+}
+
+fn schedule_pvp_timeout(ctx: &ReducerContext, match_id: &str, kind: &str, after_hours: i64) {
+    let at_ms = now_ms(ctx) + after_hours * 60 * 60 * 1000;
+    ctx.db().pvp_match_timer().insert(PvpMatchTimer {
+        scheduled_id: 0,
+        scheduled_at: Timestamp::from_micros_since_unix_epoch(at_ms * 1000).into(),
+        match_id: match_id.to_string(),
+        kind: kind.to_string(),
+    });
+}
+
+/// Moves a match to a terminal non-result status and tells both managers.
+fn close_pvp_match(ctx: &ReducerContext, mut m: PvpMatch, status: &str, actor_fid: i64, title: &str, body: &str) {
+    m.status = status.into();
+    m.finalized_at_ms = Some(now_ms(ctx));
+    ctx.db().pvp_match().id().update(m.clone());
+    let evt = append_event(ctx, &format!("pvp_match_{}", status), actor_fid, "{}".into(), Some(m.id.clone()));
+    for fid in [m.challenger_fid, m.challenged_fid] {
+        if fid == actor_fid { continue; }
+        push_inbox(ctx, fid, format!("pvp-{}-{}-{}", status, evt.id, fid), &format!("pvp_{}", status), title, body);
+    }
+}
+
+#[reducer]
+pub fn pvp_cancel_challenge(ctx: &ReducerContext, match_id: String, challenger_fid: i64) -> Result<(), ReducerError> {
+    let challenger_fid = resolve_actor(ctx, challenger_fid)?;
+    let m = ctx.db().pvp_match().id().find(&match_id).ok_or(ReducerError::MatchNotFound)?;
+    if m.challenger_fid != challenger_fid { return Err(ReducerError::NotChallenger); }
+    if m.status != "pending" { return Err(ReducerError::InvalidState); }
+    close_pvp_match(ctx, m, "cancelled", challenger_fid, "Challenge Cancelled", &format!("FID {} withdrew their challenge.", challenger_fid));
+    Ok(())
+}
+
+#[reducer]
+pub fn pvp_decline_challenge(ctx: &ReducerContext, match_id: String, challenged_fid: i64) -> Result<(), ReducerError> {
+    let challenged_fid = resolve_actor(ctx, challenged_fid)?;
+    let m = ctx.db().pvp_match().id().find(&match_id).ok_or(ReducerError::MatchNotFound)?;
+    if m.challenged_fid != challenged_fid { return Err(ReducerError::NotChallenged); }
+    if m.status != "pending" { return Err(ReducerError::InvalidState); }
+    close_pvp_match(ctx, m, "declined", challenged_fid, "Challenge Declined", &format!("FID {} declined your challenge.", challenged_fid));
+    Ok(())
+}
+
+/// Scheduled: expires unanswered challenges and abandons accepted matches
+/// that never received a result.
+#[reducer]
+pub fn pvp_match_timeout_tick(ctx: &ReducerContext, timer: PvpMatchTimer) -> Result<(), ReducerError> {
+    if ctx.sender != ctx.identity() { return Err(ReducerError::SchedulerOnly); }
+    let Some(m) = ctx.db().pvp_match().id().find(&timer.match_id) else { return Ok(()); };
+    match (timer.kind.as_str(), m.status.as_str()) {
+        ("expire", "pending") => close_pvp_match(ctx, m, "expired", 0, "Challenge Expired", "A PvP challenge expired without a response."),
+        ("abandon", "active") => {
+            let match_id = m.id.clone();
+            close_pvp_match(ctx, m, "abandoned", 0, "Match Abandoned", "No result was reported before the deadline.");
+            // A league fixture still needs a result, so the simulator decides it
+            let fixture = ctx.db().fixture().match_id().filter(&match_id).find(|f| f.status == "in_play");
+            if let Some(f) = fixture { play_fixture_sim(ctx, f)?; }
+            let tie = ctx.db().cup_tie().match_id().filter(&match_id).find(|t| t.status == "in_play");
+            if let Some(t) = tie { play_cup_tie_sim(ctx, t)?; }
+        }
+        ("dispute", "disputed") => resolve_pvp_dispute(ctx, m, None)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn pvp_config_set(ctx: &ReducerContext, challenge_expiry_hours: i64, active_deadline_hours: i64) -> Result<(), ReducerError> {
+    require_role(ctx, &["admin"])?;
+    let hours = 1..=24 * 30;
+    if !hours.contains(&challenge_expiry_hours) || !hours.contains(&active_deadline_hours) { return Err(ReducerError::OutOfRange); }
+    let cfg = PvpConfig { id: 0, challenge_expiry_hours, active_deadline_hours, updated_at_ms: now_ms(ctx) };
+    let tbl = ctx.db().pvp_config();
+    if tbl.id().find(0).is_some() { tbl.id().update(cfg); } else { tbl.insert(cfg); }
+    Ok(())
+}
  
+/// Final step for every PvP result path (confirmation, timeout, dispute, simulation).
+fn finalize_pvp_match(ctx: &ReducerContext, mut m: PvpMatch, result_json: String, via: &str, actor_fid: i64) -> Result<(), ReducerError> {
+    let (home, away) = validate_pvp_result_json(&result_json)?;
+    m.status = "finalized".into();
+    m.result_json = Some(result_json.clone());
+    m.finalized_at_ms = Some(now_ms(ctx));
+    ctx.db().pvp_match().id().update(m.clone());
+    apply_pvp_elo(ctx, &m, home, away);
+    let payload = format!("{{\"via\":\"{}\",\"result\":{}}}", via, result_json);
+    let evt = append_event(ctx, "pvp_match_finalized", actor_fid, payload, Some(m.id.clone()));
+    let body = format!("FID {} {} - {} FID {}", m.challenger_fid, home, away, m.challenged_fid);
+    for fid in [m.challenger_fid, m.challenged_fid] {
+        push_inbox(ctx, fid, format!("pvp-result-{}-{}", evt.id, fid), "pvp_result", "Match Result", &body);
+    }
+    // League fixtures are created with the home side as challenger
+    let fixture = ctx.db().fixture().match_id().filter(&m.id).find(|f| f.status == "in_play");
+    if let Some(f) = fixture { record_fixture_result(ctx, f, home, away)?; }
+    // Cup ties likewise put the home side first
+    let tie = ctx.db().cup_tie().match_id().filter(&m.id).find(|t| t.status == "in_play");
+    if let Some(t) = tie { record_cup_tie(ctx, t, home, away)?; }
+    Ok(())
+}
+
+/// K-factor for a manager with `games` rated matches behind them.
+fn elo_k_factor(games: usize, elo: i32) -> i32 {
+    if games < ELO_PROVISIONAL_GAMES { ELO_K_PROVISIONAL } else if elo >= ELO_ELITE_RATING { ELO_K_ELITE } else { ELO_K_ESTABLISHED }
+}
+
+/// Applies the ELO update for both sides of a finalized match and records it.
+fn apply_pvp_elo(ctx: &ReducerContext, m: &PvpMatch, home: i64, away: i64) {
+    let users = ctx.db().user();
+    let history = ctx.db().rating_history();
+    let elo_of = |fid: i64| users.fid().find(fid).map(|u| u.elo).unwrap_or(ELO_START);
+    let (home_elo, away_elo) = (elo_of(m.challenger_fid), elo_of(m.challenged_fid));
+    let home_score = if home > away { 1.0 } else if home < away { 0.0 } else { 0.5 };
+    let now = now_ms(ctx);
+    for (fid, elo, opp_fid, opp_elo, score) in [
+        (m.challenger_fid, home_elo, m.challenged_fid, away_elo, home_score),
+        (m.challenged_fid, away_elo, m.challenger_fid, home_elo, 1.0 - home_score),
+    ] {
+        let games = history.fid().filter(fid).count();
+        let k = elo_k_factor(games, elo);
+        let expected = 1.0 / (1.0 + 10f64.powf((opp_elo - elo) as f64 / 400.0));
+        let delta = (k as f64 * (score - expected)).round() as i32;
+        let after = (elo + delta).max(ELO_FLOOR);
+        if let Some(mut u) = users.fid().find(fid) {
+            u.elo = after;
+            users.fid().update(u);
+        }
+        let result = if score == 1.0 { "win" } else if score == 0.0 { "loss" } else { "draw" };
+        history.insert(RatingHistory {
+            id: 0,
+            fid,
+            match_id: m.id.clone(),
+            opponent_fid: opp_fid,
+            opponent_elo: opp_elo,
+            result: result.into(),
+            elo_before: elo,
+            elo_after: after,
+            delta: after - elo,
+            k_factor: k,
+            provisional: games < ELO_PROVISIONAL_GAMES,
+            ts_ms: now,
+        });
+    }
+}
+
+fn opponent_of(m: &PvpMatch, fid: i64) -> i64 {
+    if fid == m.challenger_fid { m.challenged_fid } else { m.challenger_fid }
+}
+
+fn open_pvp_dispute(ctx: &ReducerContext, mut m: PvpMatch, opened_by_fid: i64, reason: String, counter_result_json: Option<String>) {
+    let reported = m.result_json.clone().unwrap_or_default();
+    m.status = "disputed".into();
+    ctx.db().pvp_match().id().update(m.clone());
+    ctx.db().pvp_dispute().insert(PvpDispute {
+        match_id: m.id.clone(),
+        opened_by_fid,
+        reason: reason.clone(),
+        reported_result_json: reported,
+        counter_result_json,
+        status: "open".into(),
+        resolution: None,
+        resolved_result_json: None,
+        opened_at_ms: now_ms(ctx),
+        resolved_at_ms: None,
+    });
+    let evt = append_event(ctx, "pvp_result_disputed", opened_by_fid, serde_json::json!({ "reason": reason }).to_string(), Some(m.id.clone()));
+    push_inbox(ctx, opponent_of(&m, opened_by_fid), format!("pvp-dispute-{}", evt.id), "pvp_dispute", "Result Disputed", &format!("FID {} disputed the reported result.", opened_by_fid));
+    // Seasons and cups cannot wait on a moderator forever
+    let fixture_linked = ctx.db().fixture().match_id().filter(&m.id).any(|f| f.status == "in_play");
+    let tie_linked = ctx.db().cup_tie().match_id().filter(&m.id).any(|t| t.status == "in_play");
+    if fixture_linked || tie_linked { schedule_pvp_timeout(ctx, &m.id, "dispute", FIXTURE_DISPUTE_HOURS); }
+}
+
+/// First report moves the match to "awaiting_confirmation". A report from the
+/// opponent either confirms (same score) or opens a dispute (different score).
  #[reducer]
- pub fn pvp_submit_result(ctx: &ReducerContext, match_id: String, reporter_fid: i64, result_json: String) {
+ pub fn pvp_submit_result(ctx: &ReducerContext, match_id: String, reporter_fid: i64, result_json: String) -> Result<(), ReducerError> {
+     let reporter_fid = resolve_actor(ctx, reporter_fid)?;
      let tbl = ctx.db().pvp_match();
-     let mut m = tbl.id().find(&match_id).ok_or("match_not_found").unwrap();
-     if m.status != "active" { panic!("invalid_state"); }
-     if reporter_fid != m.challenger_fid && reporter_fid != m.challenged_fid { panic!("not_participant"); }
-     if let Err(code) = validate_pvp_result_json(&result_json) { panic!("{}", code); }
-     m.status = "finalized".into();
-     m.result_json = Some(result_json.clone());
-     tbl.id().update(m.clone());
-     append_event(ctx, "pvp_result_submitted", reporter_fid, result_json, Some(match_id));
+     let mut m = tbl.id().find(&match_id).ok_or(ReducerError::MatchNotFound)?;
+     if reporter_fid != m.challenger_fid && reporter_fid != m.challenged_fid { return Err(ReducerError::NotParticipant); }
+     // An NPC can never confirm or dispute, so its matches are only ever simulated
+     if is_npc_fid(ctx, m.challenger_fid) || is_npc_fid(ctx, m.challenged_fid) { return Err(ReducerError::NpcMatch); }
+     let score = validate_pvp_result_json(&result_json)?;
+    match m.status.as_str() {
+        "active" => {
+            let now = now_ms(ctx);
+            m.status = "awaiting_confirmation".into();
+            m.result_json = Some(result_json.clone());
+            m.reported_by_fid = Some(reporter_fid);
+            m.reported_at_ms = Some(now);
+            tbl.id().update(m.clone());
+            let deadline = now + PVP_CONFIRM_HOURS * 60 * 60 * 1000;
+            ctx.db().pvp_confirm_timer().insert(PvpConfirmTimer {
+                scheduled_id: 0,
+                scheduled_at: Timestamp::from_micros_since_unix_epoch(deadline * 1000).into(),
+                match_id: match_id.clone(),
+                reported_at_ms: now,
+            });
+            let evt = append_event(ctx, "pvp_result_submitted", reporter_fid, result_json, Some(match_id));
+            push_inbox(ctx, opponent_of(&m, reporter_fid), format!("pvp-confirm-{}", evt.id), "pvp_confirm", "Confirm Result", &format!("FID {} reported {} - {}. Confirm or dispute within {}h.", reporter_fid, score.0, score.1, PVP_CONFIRM_HOURS));
+            Ok(())
+        }
+        "awaiting_confirmation" => {
+            if m.reported_by_fid == Some(reporter_fid) { return Err(ReducerError::AlreadyReported); }
+            let reported = m.result_json.as_deref().map(validate_pvp_result_json).transpose()?;
+            if reported == Some(score) {
+                let reported_json = m.result_json.clone().unwrap_or_default();
+                finalize_pvp_match(ctx, m, reported_json, "confirmed", reporter_fid)
+            } else {
+                open_pvp_dispute(ctx, m, reporter_fid, "conflicting_report".into(), Some(result_json));
+                Ok(())
+            }
+        }
+        _ => Err(ReducerError::InvalidState),
+    }
  }
+
+#[reducer]
+pub fn pvp_confirm_result(ctx: &ReducerContext, match_id: String, fid: i64) -> Result<(), ReducerError> {
+    let fid = resolve_actor(ctx, fid)?;
+    let m = ctx.db().pvp_match().id().find(&match_id).ok_or(ReducerError::MatchNotFound)?;
+    if fid != m.challenger_fid && fid != m.challenged_fid { return Err(ReducerError::NotParticipant); }
+    if m.status != "awaiting_confirmation" { return Err(ReducerError::InvalidState); }
+    if m.reported_by_fid == Some(fid) { return Err(ReducerError::AlreadyReported); }
+    let reported = m.result_json.clone().unwrap_or_default();
+    finalize_pvp_match(ctx, m, reported, "confirmed", fid)
+}
+
+#[reducer]
+pub fn pvp_dispute_result(ctx: &ReducerContext, match_id: String, fid: i64, reason: String) -> Result<(), ReducerError> {
+    let fid = resolve_actor(ctx, fid)?;
+    let m = ctx.db().pvp_match().id().find(&match_id).ok_or(ReducerError::MatchNotFound)?;
+    if fid != m.challenger_fid && fid != m.challenged_fid { return Err(ReducerError::NotParticipant); }
+    if m.status != "awaiting_confirmation" { return Err(ReducerError::InvalidState); }
+    if m.reported_by_fid == Some(fid) { return Err(ReducerError::AlreadyReported); }
+    open_pvp_dispute(ctx, m, fid, reason, None);
+    Ok(())
+}
+
+/// Closes an open dispute. With `result_json` the operator's score stands;
+/// without it the match is replayed by the server simulation.
+#[reducer]
+pub fn pvp_resolve_dispute(ctx: &ReducerContext, match_id: String, result_json: Option<String>) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend", "moderator"])?;
+    let m = ctx.db().pvp_match().id().find(&match_id).ok_or(ReducerError::MatchNotFound)?;
+    resolve_pvp_dispute(ctx, m, result_json)
+}
+
+fn resolve_pvp_dispute(ctx: &ReducerContext, m: PvpMatch, result_json: Option<String>) -> Result<(), ReducerError> {
+    let match_id = m.id.clone();
+    let disputes = ctx.db().pvp_dispute();
+    let mut d = disputes.match_id().find(&match_id).ok_or(ReducerError::DisputeNotFound)?;
+    if d.status != "open" || m.status != "disputed" { return Err(ReducerError::InvalidState); }
+    let (resolution, final_json) = match result_json {
+        Some(json) => {
+            validate_pvp_result_json(&json)?;
+            ("operator", json)
+        }
+        None => {
+            let res = simulate_pvp_match(ctx, &m);
+            apply_sim_to_players(ctx, m.challenger_fid, m.challenged_fid, &res)?;
+            ("sim", serde_json::to_string(&res).unwrap_or_else(|_| "{}".into()))
+        }
+    };
+    d.status = "resolved".into();
+    d.resolution = Some(resolution.into());
+    d.resolved_result_json = Some(final_json.clone());
+    d.resolved_at_ms = Some(now_ms(ctx));
+    disputes.match_id().update(d);
+    append_event(ctx, "pvp_dispute_resolved", 0, format!("{{\"resolution\":\"{}\"}}", resolution), Some(match_id));
+    finalize_pvp_match(ctx, m, final_json, resolution, 0)
+}
+
+/// Scheduled: finalizes a report the opponent neither confirmed nor disputed in time.
+#[reducer]
+pub fn pvp_confirm_timeout_tick(ctx: &ReducerContext, timer: PvpConfirmTimer) -> Result<(), ReducerError> {
+    if ctx.sender != ctx.identity() { return Err(ReducerError::SchedulerOnly); }
+    let Some(m) = ctx.db().pvp_match().id().find(&timer.match_id) else { return Ok(()); };
+    // Confirmed, disputed or re-reported since this timer was armed
+    if m.status != "awaiting_confirmation" || m.reported_at_ms != Some(timer.reported_at_ms) { return Ok(()); }
+    let reporter = m.reported_by_fid.unwrap_or(0);
+    let reported = m.result_json.clone().unwrap_or_default();
+    finalize_pvp_match(ctx, m, reported, "timeout", reporter)
+}
  
  #[reducer]
- pub fn mark_tx_used(ctx: &ReducerContext, tx_hash: String, fid: i64, endpoint: String) {
+ pub fn mark_tx_used(ctx: &ReducerContext, tx_hash: String, fid: i64, endpoint: String) -> Result<(), ReducerError> {
+     require_role(ctx, &["backend"])?;
      let tx_table = ctx.db().transaction_used();
-     // Idempotency: panic if already used
+     // Idempotency: reject if already used
      if tx_table.tx_hash().find(&tx_hash).is_some() {
-         panic!("tx_already_used");
+         return Err(ReducerError::TxAlreadyUsed);
      }
      // Mark transaction as used
      tx_table.insert(TransactionUsed {
@@ -563,8 +2029,39 @@ fn on_item_transferred(ctx: &ReducerContext, item_id: &str, to_fid: i64) {
          used_by_fid: fid,
          endpoint,
      });
+     Ok(())
  }
 
+// --- Balance Reducers ---
+
+/// Credits an on-chain FBC deposit once the backend has verified `tx_hash`.
+#[reducer]
+pub fn balance_deposit(ctx: &ReducerContext, tx_hash: String, fid: i64, amount_wei: String) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend"])?;
+    let tx_table = ctx.db().transaction_used();
+    if tx_table.tx_hash().find(&tx_hash).is_some() { return Err(ReducerError::TxAlreadyUsed); }
+    let amount = wei::parse_positive(&amount_wei)?;
+    credit_available(ctx, fid, amount)?;
+    record_ledger(ctx, fid, "deposit", "credit", amount, None, &tx_hash);
+    tx_table.insert(TransactionUsed { tx_hash, used_at_ms: now_ms(ctx), used_by_fid: fid, endpoint: "balance_deposit".into() });
+    Ok(())
+}
+
+/// Debits a withdrawal before the backend sends the on-chain payout. `ref_id` is
+/// single-use so a retried request cannot debit twice.
+#[reducer]
+pub fn balance_withdraw(ctx: &ReducerContext, fid: i64, amount_wei: String, ref_id: String) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend"])?;
+    let tx_table = ctx.db().transaction_used();
+    let used_key = format!("withdraw:{}", ref_id);
+    if tx_table.tx_hash().find(&used_key).is_some() { return Err(ReducerError::TxAlreadyUsed); }
+    let amount = wei::parse_positive(&amount_wei)?;
+    debit_available(ctx, fid, amount)?;
+    record_ledger(ctx, fid, "withdraw", "debit", amount, None, &ref_id);
+    tx_table.insert(TransactionUsed { tx_hash: used_key, used_at_ms: now_ms(ctx), used_by_fid: fid, endpoint: "balance_withdraw".into() });
+    Ok(())
+}
+
 // --- NPC & Squad Reducers ---
 
 #[reducer]
@@ -576,12 +2073,14 @@ pub fn npc_create(
     difficulty_tier: i16,
     budget_fbc_wei: String,
     persona_json: String,
-) {
+) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend"])?;
+    let budget_fbc_wei = wei::parse(&budget_fbc_wei)?;
     let now = now_ms(ctx);
 
     // Upsert user with NPC flags
     let users = ctx.db().user();
-    match users.fid().find(&npc_fid) {
+    match users.fid().find(npc_fid) {
         Some(mut u) => {
             u.is_npc = true;
             u.display_name = Some(display_name.clone());
@@ -594,7 +2093,7 @@ pub fn npc_create(
                 wallet: None,
                 created_at_ms: now,
                 is_npc: true,
-                elo: 1000,
+                elo: ELO_START,
                 display_name: Some(display_name.clone()),
                 ai_persona_json: Some(persona_json.clone()),
             });
@@ -603,11 +2102,11 @@ pub fn npc_create(
 
     // Upsert NPC registry row
     let tbl = ctx.db().npc_registry();
-    match tbl.npc_fid().find(&npc_fid) {
+    match tbl.npc_fid().find(npc_fid) {
         Some(mut n) => {
             n.ai_seed = ai_seed;
             n.difficulty_tier = difficulty_tier;
-            n.budget_fbc_wei = budget_fbc_wei.clone();
+            n.budget_fbc_wei = budget_fbc_wei;
             n.persona = persona_json;
             n.manager_confidence = 50;
             n.pressure_level = 0;
@@ -620,7 +2119,7 @@ pub fn npc_create(
         None => {
             tbl.insert(NpcRegistry {
                 npc_fid,
-                token_id: None,
+                token_id: String::new(),
                 ai_seed,
                 difficulty_tier,
                 budget_fbc_wei,
@@ -635,10 +2134,12 @@ pub fn npc_create(
             });
         }
     }
+    Ok(())
 }
 
 #[reducer]
-pub fn npc_mint_token(ctx: &ReducerContext, npc_fid: i64, owner_fid: i64) {
+pub fn npc_mint_token(ctx: &ReducerContext, npc_fid: i64, owner_fid: i64) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend"])?;
     let now = now_ms(ctx);
     let token_id = format!("npc-{}", npc_fid);
 
@@ -657,30 +2158,29 @@ pub fn npc_mint_token(ctx: &ReducerContext, npc_fid: i64, owner_fid: i64) {
     }
 
     // Update registry owner and token id
-    if let Some(mut n) = ctx.db().npc_registry().npc_fid().find(&npc_fid) {
+    if let Some(mut n) = ctx.db().npc_registry().npc_fid().find(npc_fid) {
         n.owner_fid = Some(owner_fid);
-        n.token_id = Some(token_id);
+        n.token_id = token_id;
         ctx.db().npc_registry().npc_fid().update(n);
     }
+    Ok(())
 }
 
 #[reducer]
-pub fn npc_assign_for_user(ctx: &ReducerContext, user_fid: i64, count: i16) {
-    if count <= 0 { return; }
+pub fn npc_assign_for_user(ctx: &ReducerContext, user_fid: i64, count: i16) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend"])?;
+    if count <= 0 { return Ok(()); }
 
     let now = now_ms(ctx);
     let mut remaining = count as i32;
 
-    // Collect available NPCs (active, no owner)
-    let mut available: Vec<NpcRegistry> = Vec::new();
-    for n in ctx.db().npc_registry().iter() {
-        if n.active && n.owner_fid.is_none() {
-            available.push(n);
-        }
-    }
+    // Collect available NPCs (active, no owner); unowned NPCs have no token yet
+    let available: Vec<NpcRegistry> = ctx.db().npc_registry().token_id().filter("")
+        .filter(|n| n.active && n.owner_fid.is_none())
+        .collect();
 
     if (available.len() as i32) < remaining {
-        panic!("insufficient_npc_pool");
+        return Err(ReducerError::InsufficientNpcPool);
     }
 
     for n in available.into_iter() {
@@ -711,13 +2211,14 @@ pub fn npc_assign_for_user(ctx: &ReducerContext, user_fid: i64, count: i16) {
             });
         }
         // Update registry owner
-        if let Some(mut row) = ctx.db().npc_registry().npc_fid().find(&n.npc_fid) {
+        if let Some(mut row) = ctx.db().npc_registry().npc_fid().find(n.npc_fid) {
             row.owner_fid = Some(user_fid);
-            row.token_id = Some(token_id);
+            row.token_id = token_id;
             ctx.db().npc_registry().npc_fid().update(row);
         }
         remaining -= 1;
     }
+    Ok(())
 }
 
 #[reducer]
@@ -726,13 +2227,15 @@ pub fn npc_update_state(
     npc_fid: i64,
     next_decision_at_ms: i64,
     budget_fbc_wei: String,
-) {
-    if let Some(mut n) = ctx.db().npc_registry().npc_fid().find(&npc_fid) {
+) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend"])?;
+    if let Some(mut n) = ctx.db().npc_registry().npc_fid().find(npc_fid) {
         n.next_decision_at_ms = next_decision_at_ms;
-        n.budget_fbc_wei = budget_fbc_wei;
+        n.budget_fbc_wei = wei::parse(&budget_fbc_wei)?;
         n.last_active_ms = now_ms(ctx);
         ctx.db().npc_registry().npc_fid().update(n);
     }
+    Ok(())
 }
 
 #[reducer]
@@ -744,7 +2247,8 @@ pub fn squad_mint_from_farcaster(
     intelligence_score: i32,
     rank: String,
     persona_json: String,
-) {
+) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend"])?;
     let now = now_ms(ctx);
     let token_id = format!("squad-{}", source_fid);
     let squad_id = token_id.clone();
@@ -783,7 +2287,7 @@ pub fn squad_mint_from_farcaster(
         Some(mut s) => {
             s.source_fid = source_fid;
             s.followers = followers;
-            s.intelligence_score = if intelligence_score < 0 { 0 } else if intelligence_score > 100 { 100 } else { intelligence_score };
+            s.intelligence_score = intelligence_score.clamp(0, 100);
             s.rank = rank.clone();
             s.persona = persona_json.clone();
             s.token_id = token_id.clone();
@@ -796,51 +2300,1182 @@ pub fn squad_mint_from_farcaster(
                 squad_id: squad_id.clone(),
                 source_fid,
                 followers,
-                intelligence_score: if intelligence_score < 0 { 0 } else if intelligence_score > 100 { 100 } else { intelligence_score },
+                intelligence_score: intelligence_score.clamp(0, 100),
                 rank,
                 persona: persona_json,
-                token_id: token_id,
+                token_id,
                 owner_fid,
                 active: true,
             });
         }
     }
+    Ok(())
+}
+
+// --- Player State Reducers ---
+
+// Per-match events for a single player, as sent to `player_state_apply_match`
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct PlayerMatchEvents {
+    pub goals: i32,
+    pub assists: i32,
+    pub yellow_cards: i32,
+    pub red_card: bool,
+    pub injury: Option<String>, // minor|moderate|severe
+}
+
+fn injury_rank(status: &str) -> i32 {
+    match status { "minor" => 1, "moderate" => 2, "severe" => 3, _ => 0 }
+}
+
+fn injury_days(status: &str) -> i64 {
+    match status { "minor" => 3, "moderate" => 14, "severe" => 42, _ => 0 }
+}
+
+fn default_player_state(player_id: &str) -> PlayerState {
+    PlayerState {
+        player_id: player_id.to_string(),
+        age_years: 24,
+        morale: 60,
+        fatigue: 0,
+        injury_status: "none".into(),
+        injury_end_ms: None,
+        satisfaction: 60,
+        loyalty: 50,
+        minutes_played_7d: 0,
+        matches_played_7d: 0,
+        matches_benched_7d: 0,
+        last_match_at_ms: None,
+        age_decline: 0,
+    }
+}
+
+fn require_player_item(ctx: &ReducerContext, player_id: &str) -> Result<InventoryItem, ReducerError> {
+    let item = ctx.db().inventory_item().item_id().find(player_id.to_string()).ok_or(ReducerError::ItemNotFound)?;
+    if item.item_type != "player" { return Err(ReducerError::NotAPlayer); }
+    Ok(item)
 }
 
-// --- Player State Reducers (stubs) ---
+/// Folds one match into a player's state. Creates a default state for players
+/// that were never profiled so match processing never stalls on a missing row.
+fn apply_match_to_player(ctx: &ReducerContext, player_id: &str, minutes_played: i32, benched: bool, result: &str, events: &PlayerMatchEvents) -> Result<PlayerState, ReducerError> {
+    if !(0..=120).contains(&minutes_played) { return Err(ReducerError::OutOfRange); }
+    if !matches!(result, "win" | "draw" | "loss") { return Err(ReducerError::InvalidResult); }
+    require_player_item(ctx, player_id)?;
+    let now = now_ms(ctx);
+    let states = ctx.db().player_state();
+    let existing = states.player_id().find(player_id.to_string());
+    let is_new = existing.is_none();
+    let mut st = existing.unwrap_or_else(|| default_player_state(player_id));
+    let played = minutes_played > 0;
+
+    // Fatigue: a full 90 minutes adds 35
+    st.fatigue = (st.fatigue + minutes_played * 35 / 90).clamp(0, 100);
+
+    // Morale follows the result and personal contribution
+    let mut morale = st.morale;
+    if played || benched {
+        morale += match result { "win" => 5, "loss" => -5, _ => 0 };
+    }
+    morale += events.goals.clamp(0, 5) * 3 + events.assists.clamp(0, 5) * 2;
+    morale -= events.yellow_cards.clamp(0, 2) + if events.red_card { 8 } else { 0 };
+    if benched { morale -= 3; }
+    st.morale = morale.clamp(0, 100);
+
+    // Satisfaction tracks playing time; repeated benching hurts more
+    let mut satisfaction = st.satisfaction;
+    if benched {
+        satisfaction -= 4;
+        if st.matches_benched_7d >= 2 { satisfaction -= 3; }
+    } else if minutes_played >= 60 {
+        satisfaction += 3;
+    } else if played {
+        satisfaction += 1;
+    }
+    st.satisfaction = satisfaction.clamp(0, 100);
+    if played && result == "win" { st.loyalty = (st.loyalty + 1).clamp(0, 100); }
+
+    // Rolling 7-day counters (rolled off by the recovery tick)
+    st.minutes_played_7d += minutes_played;
+    if played { st.matches_played_7d += 1; }
+    if benched { st.matches_benched_7d += 1; }
+    if played || benched {
+        st.last_match_at_ms = Some(now);
+        ctx.db().player_match_log().insert(PlayerMatchLog { id: 0, player_id: player_id.to_string(), minutes_played, benched, ts_ms: now });
+    }
+
+    // A new injury only replaces a milder one
+    if let Some(sev) = events.injury.as_deref() {
+        if injury_rank(sev) == 0 { return Err(ReducerError::InvalidPayload); }
+        if injury_rank(sev) > injury_rank(&st.injury_status) {
+            st.injury_status = sev.to_string();
+            st.injury_end_ms = Some(now + injury_days(sev) * 24 * 60 * 60 * 1000);
+        }
+    }
+
+    if is_new { states.insert(st.clone()); } else { states.player_id().update(st.clone()); }
+    Ok(st)
+}
 
 #[reducer]
 pub fn player_profile_init(
-    _ctx: &ReducerContext,
-    _player_id: String,
-    _age_years: i16,
-    _morale: i32,
-    _fatigue: i32,
-    _satisfaction: i32,
-    _loyalty: i32,
-) { unimplemented!("player_profile_init not implemented yet"); }
+    ctx: &ReducerContext,
+    player_id: String,
+    age_years: i16,
+    morale: i32,
+    fatigue: i32,
+    satisfaction: i32,
+    loyalty: i32,
+) -> Result<(), ReducerError> {
+    let item = require_player_item(ctx, &player_id)?;
+    if !is_trusted_service(ctx) && linked_fid(ctx) != Some(item.owner_fid) { return Err(ReducerError::NotOwner); }
+    if !(15..=45).contains(&age_years) { return Err(ReducerError::OutOfRange); }
+    if [morale, fatigue, satisfaction, loyalty].iter().any(|v| !(0..=100).contains(v)) { return Err(ReducerError::OutOfRange); }
+    let states = ctx.db().player_state();
+    if states.player_id().find(&player_id).is_some() { return Err(ReducerError::PlayerStateExists); }
+    states.insert(PlayerState { age_years, morale, fatigue, satisfaction, loyalty, ..default_player_state(&player_id) });
+    append_event(ctx, "player_profile_created", item.owner_fid, "{}".into(), Some(player_id));
+    Ok(())
+}
 
 #[reducer]
 pub fn player_state_apply_match(
-    _ctx: &ReducerContext,
-    _player_id: String,
-    _minutes_played: i32,
-    _benched: bool,
-    _result: String,
-    _events_json: String,
-) { unimplemented!("player_state_apply_match not implemented yet"); }
+    ctx: &ReducerContext,
+    player_id: String,
+    minutes_played: i32,
+    benched: bool,
+    result: String,
+    events_json: String,
+) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend"])?;
+    let events: PlayerMatchEvents = if events_json.trim().is_empty() {
+        PlayerMatchEvents::default()
+    } else {
+        serde_json::from_str(&events_json).map_err(|_| ReducerError::InvalidJson)?
+    };
+    let st = apply_match_to_player(ctx, &player_id, minutes_played, benched, &result, &events)?;
+    let owner = require_player_item(ctx, &player_id)?.owner_fid;
+    append_event(ctx, "player_state_updated", owner, serde_json::to_string(&st).unwrap_or("{}".into()), Some(player_id));
+    Ok(())
+}
+
+fn ensure_player_tick_schedules(ctx: &ReducerContext) {
+    if ctx.db().player_recover_schedule().count() == 0 {
+        let every = std::time::Duration::from_secs(PLAYER_RECOVER_TICK_HOURS * 60 * 60);
+        ctx.db().player_recover_schedule().insert(PlayerRecoverSchedule { scheduled_id: 0, scheduled_at: every.into() });
+    }
+    if ctx.db().player_age_schedule().count() == 0 {
+        let every = std::time::Duration::from_secs(SEASON_DAYS * 24 * 60 * 60);
+        ctx.db().player_age_schedule().insert(PlayerAgeSchedule { scheduled_id: 0, scheduled_at: every.into() });
+    }
+}
+
+/// Moves `value` a fraction of the way back toward `baseline` (at least one point).
+fn drift_toward(value: i32, baseline: i32) -> i32 {
+    let diff = baseline - value;
+    if diff == 0 { return value; }
+    let step = diff / 8;
+    value + if step == 0 { diff.signum() } else { step }
+}
+
+/// Per-season decline from age; young players hold steady.
+fn age_decline(age_years: i16) -> i32 {
+    match age_years {
+        ..=29 => 0,
+        30..=32 => 2,
+        33..=34 => 4,
+        _ => 7,
+    }
+}
 
+/// Scheduled every `PLAYER_RECOVER_TICK_HOURS`: fatigue decays, healed injuries
+/// clear, morale/satisfaction drift back to baseline and the 7-day window rolls.
 #[reducer]
-pub fn player_state_recover_tick(_ctx: &ReducerContext, _now_ms: i64) {
-    unimplemented!("player_state_recover_tick not implemented yet");
+pub fn player_state_recover_tick(ctx: &ReducerContext, _schedule: PlayerRecoverSchedule) -> Result<(), ReducerError> {
+    if ctx.sender != ctx.identity() { return Err(ReducerError::SchedulerOnly); }
+    let now = now_ms(ctx);
+    let states = ctx.db().player_state();
+
+    // Roll appearances older than 7 days off the counters
+    let cutoff = now - 7 * 24 * 60 * 60 * 1000;
+    let logs = ctx.db().player_match_log();
+    let expired: Vec<PlayerMatchLog> = logs.ts_ms().filter(..cutoff).collect();
+    for log in expired {
+        if let Some(mut st) = states.player_id().find(&log.player_id) {
+            st.minutes_played_7d = (st.minutes_played_7d - log.minutes_played).max(0);
+            if log.minutes_played > 0 { st.matches_played_7d = (st.matches_played_7d - 1).max(0); }
+            if log.benched { st.matches_benched_7d = (st.matches_benched_7d - 1).max(0); }
+            states.player_id().update(st);
+        }
+        logs.id().delete(log.id);
+    }
+
+    let rows: Vec<PlayerState> = states.iter().collect();
+    for mut st in rows {
+        let before = st.clone();
+        st.fatigue = (st.fatigue - 12).max(0);
+        st.morale = drift_toward(st.morale, 60);
+        st.satisfaction = drift_toward(st.satisfaction, 60);
+        let healed = st.injury_status != "none" && st.injury_end_ms.is_some_and(|end| end <= now);
+        if healed {
+            st.injury_status = "none".into();
+            st.injury_end_ms = None;
+        }
+        if st.fatigue == before.fatigue && st.morale == before.morale && st.satisfaction == before.satisfaction && !healed { continue; }
+        states.player_id().update(st.clone());
+        if healed {
+            let owner = ctx.db().inventory_item().item_id().find(&st.player_id).map(|i| i.owner_fid).unwrap_or(0);
+            let evt = append_event(ctx, "player_recovered", owner, format!("{{\"injury\":\"{}\"}}", before.injury_status), Some(st.player_id.clone()));
+            if owner != 0 {
+                push_inbox(ctx, owner, format!("player-recovered-{}", evt.id), "player_recovered", "Player Recovered", "One of your players is fit again.");
+            }
+        }
+    }
+    Ok(())
 }
 
+/// Scheduled every `SEASON_DAYS`: players get a year older and veterans decline.
 #[reducer]
-pub fn player_age_tick(_ctx: &ReducerContext) { unimplemented!("player_age_tick not implemented yet"); }
+pub fn player_age_tick(ctx: &ReducerContext, _schedule: PlayerAgeSchedule) -> Result<(), ReducerError> {
+    if ctx.sender != ctx.identity() { return Err(ReducerError::SchedulerOnly); }
+    let states = ctx.db().player_state();
+    let rows: Vec<PlayerState> = states.iter().collect();
+    let mut aged = 0;
+    for mut st in rows {
+        st.age_years = st.age_years.saturating_add(1);
+        let decline = age_decline(st.age_years);
+        st.age_decline = (st.age_decline + decline).clamp(0, 100);
+        states.player_id().update(st.clone());
+        aged += 1;
+        if decline > 0 {
+            let owner = ctx.db().inventory_item().item_id().find(&st.player_id).map(|i| i.owner_fid).unwrap_or(0);
+            append_event(ctx, "player_declined", owner, format!("{{\"age_years\":{},\"decline\":{}}}", st.age_years, decline), Some(st.player_id.clone()));
+        }
+    }
+    append_event(ctx, "player_age_tick", 0, format!("{{\"players\":{}}}", aged), None);
+    Ok(())
+}
+
+// --- Lineups ---
+
+pub const FORMATIONS: [&str; 7] = ["4-3-3", "4-4-2", "3-5-2", "4-2-3-1", "5-3-2", "3-4-3", "4-5-1"];
+pub const DEFAULT_FORMATION: &str = "4-4-2";
+pub const LINEUP_FIRST_SUB_SLOT: u8 = 11;
+pub const LINEUP_MAX_SUBS: u8 = 7;
+
+/// Slot positions for a formation: the keeper, then the first line defends,
+/// the last line attacks and anything between is midfield.
+fn formation_positions(formation: &str) -> Option<Vec<&'static str>> {
+    if !FORMATIONS.contains(&formation) { return None; }
+    let lines: Vec<usize> = formation.split('-').filter_map(|n| n.parse().ok()).collect();
+    let mut positions = vec!["GK"];
+    for (i, &n) in lines.iter().enumerate() {
+        let pos = if i == 0 { "DEF" } else if i == lines.len() - 1 { "FWD" } else { "MID" };
+        positions.extend(std::iter::repeat_n(pos, n));
+    }
+    Some(positions)
+}
+
+fn load_lineup(ctx: &ReducerContext, fid: i64) -> Lineup {
+    ctx.db().lineup().fid().find(fid).unwrap_or(Lineup { fid, formation: DEFAULT_FORMATION.into(), captain_item_id: String::new(), updated_at_ms: now_ms(ctx) })
+}
+
+fn store_lineup(ctx: &ReducerContext, mut l: Lineup) {
+    l.updated_at_ms = now_ms(ctx);
+    let tbl = ctx.db().lineup();
+    if tbl.fid().find(l.fid).is_some() { tbl.fid().update(l); } else { tbl.insert(l); }
+}
+
+/// Checks that `fid` can field `item_id` at `position` (SUB fits anyone).
+/// Players without a card predate player_card and fit any outfield slot.
+fn check_lineup_player(ctx: &ReducerContext, fid: i64, item_id: &str, position: &str) -> Result<(), ReducerError> {
+    let item = ctx.db().inventory_item().item_id().find(item_id.to_string()).ok_or(ReducerError::ItemNotFound)?;
+    if item.owner_fid != fid { return Err(ReducerError::NotOwner); }
+    if item.item_type != "player" { return Err(ReducerError::NotAPlayer); }
+    if ctx.db().item_lock().item_id().find(item_id.to_string()).is_some() { return Err(ReducerError::ItemLocked); }
+    if ctx.db().player_state().player_id().find(item_id.to_string()).is_some_and(|st| st.injury_status != "none") { return Err(ReducerError::PlayerInjured); }
+    if position == "SUB" { return Ok(()); }
+    let fits = match ctx.db().player_card().item_id().find(item_id.to_string()) {
+        Some(card) => card.positions.iter().any(|p| p == position),
+        None => position != "GK",
+    };
+    if fits { Ok(()) } else { Err(ReducerError::PositionMismatch) }
+}
+
+/// Drops every lineup slot holding `item_id` (sold, moved or cleared) and the
+/// captaincy with it.
+fn remove_from_lineups(ctx: &ReducerContext, item_id: &str) {
+    let slots = ctx.db().lineup_slot();
+    let held: Vec<LineupSlot> = slots.item_id().filter(item_id).collect();
+    for s in held {
+        slots.id().delete(s.id);
+        if let Some(mut l) = ctx.db().lineup().fid().find(s.fid).filter(|l| l.captain_item_id == item_id) {
+            l.captain_item_id.clear();
+            store_lineup(ctx, l);
+        }
+    }
+}
+
+/// Sets the formation. Starters whose slot changes position are benched off
+/// the sheet; substitutes are kept.
+#[reducer]
+pub fn lineup_set_formation(ctx: &ReducerContext, fid: i64, formation: String) -> Result<(), ReducerError> {
+    let fid = resolve_actor(ctx, fid)?;
+    let positions = formation_positions(&formation).ok_or(ReducerError::InvalidFormation)?;
+    let mut lineup = load_lineup(ctx, fid);
+    lineup.formation = formation;
+    let slots = ctx.db().lineup_slot();
+    let starters: Vec<LineupSlot> = slots.fid_slot().filter(fid).filter(|s| s.slot < LINEUP_FIRST_SUB_SLOT).collect();
+    for s in starters {
+        if positions[s.slot as usize] == s.position { continue; }
+        if lineup.captain_item_id == s.item_id { lineup.captain_item_id.clear(); }
+        slots.id().delete(s.id);
+    }
+    store_lineup(ctx, lineup);
+    Ok(())
+}
+
+/// Puts `item_id` in `slot`, moving it from any other slot. An empty
+/// `item_id` clears the slot.
+#[reducer]
+pub fn lineup_assign(ctx: &ReducerContext, fid: i64, slot: u8, item_id: String) -> Result<(), ReducerError> {
+    let fid = resolve_actor(ctx, fid)?;
+    if slot >= LINEUP_FIRST_SUB_SLOT + LINEUP_MAX_SUBS { return Err(ReducerError::InvalidSlot); }
+    let mut lineup = load_lineup(ctx, fid);
+    let positions = formation_positions(&lineup.formation).ok_or(ReducerError::InvalidFormation)?;
+    let position = if slot < LINEUP_FIRST_SUB_SLOT { positions[slot as usize] } else { "SUB" };
+    if !item_id.is_empty() { check_lineup_player(ctx, fid, &item_id, position)?; }
+
+    let slots = ctx.db().lineup_slot();
+    if let Some(current) = slots.fid_slot().filter((fid, slot)).next() {
+        if lineup.captain_item_id == current.item_id { lineup.captain_item_id.clear(); }
+        slots.id().delete(current.id);
+    }
+    if !item_id.is_empty() {
+        let elsewhere: Vec<u64> = slots.item_id().filter(&item_id).filter(|s| s.fid == fid).map(|s| s.id).collect();
+        for id in elsewhere { slots.id().delete(id); }
+        // A captain dropped to the bench loses the armband
+        if position == "SUB" && lineup.captain_item_id == item_id { lineup.captain_item_id.clear(); }
+        slots.insert(LineupSlot { id: 0, fid, slot, position: position.into(), item_id });
+    }
+    store_lineup(ctx, lineup);
+    Ok(())
+}
+
+/// Hands the armband to a starter; an empty `item_id` removes the captain.
+#[reducer]
+pub fn lineup_set_captain(ctx: &ReducerContext, fid: i64, item_id: String) -> Result<(), ReducerError> {
+    let fid = resolve_actor(ctx, fid)?;
+    let mut lineup = load_lineup(ctx, fid);
+    if !item_id.is_empty() {
+        let starting = ctx.db().lineup_slot().item_id().filter(&item_id).any(|s| s.fid == fid && s.slot < LINEUP_FIRST_SUB_SLOT);
+        if !starting { return Err(ReducerError::NotInLineup); }
+    }
+    lineup.captain_item_id = item_id;
+    store_lineup(ctx, lineup);
+    Ok(())
+}
+
+// --- Match Simulation ---
+
+/// Rating for legacy player items minted before player_card existed.
+pub const DEFAULT_PLAYER_RATING: i32 = 60;
+pub const SIM_LINEUP_SIZE: usize = 11;
+
+/// Fields the manager's lineup starters if they have set any; otherwise picks
+/// the fittest eleven they own, ranked by age-adjusted rating, freshness and
+/// then id for stability. Injured players sit out either way.
+fn sim_team_for(ctx: &ReducerContext, fid: i64) -> sim::SimTeam {
+    let states = ctx.db().player_state();
+    let cards = ctx.db().player_card();
+    let owned: Vec<InventoryItem> = ctx.db().inventory_item().owner_fid().filter(fid).collect();
+    let to_sim = |item_id: &String| {
+        let st = states.player_id().find(item_id).unwrap_or_else(|| default_player_state(item_id));
+        if st.injury_status != "none" { return None; }
+        let overall = cards.item_id().find(item_id).map_or(DEFAULT_PLAYER_RATING, |c| c.overall);
+        let rating = overall * (100 - st.age_decline.clamp(0, 100)) / 100;
+        Some(sim::SimPlayer { player_id: item_id.clone(), rating, fatigue: st.fatigue, morale: st.morale })
+    };
+    let starters: Vec<String> = ctx.db().lineup_slot().fid_slot().filter(fid)
+        .filter(|s| s.slot < LINEUP_FIRST_SUB_SLOT)
+        .map(|s| s.item_id)
+        .collect();
+    let players: Vec<sim::SimPlayer> = if starters.is_empty() {
+        let mut best: Vec<sim::SimPlayer> = owned.iter().filter(|i| i.item_type == "player").filter_map(|i| to_sim(&i.item_id)).collect();
+        best.sort_by(|a, b| b.rating.cmp(&a.rating).then(a.fatigue.cmp(&b.fatigue)).then(a.player_id.cmp(&b.player_id)));
+        best.truncate(SIM_LINEUP_SIZE);
+        best
+    } else {
+        starters.iter().filter_map(to_sim).collect()
+    };
+
+    // Squad rating: best owned squad's intelligence, else the NPC's difficulty tier
+    let squads = ctx.db().squad_registry();
+    let squad_rating = owned.iter()
+        .filter(|i| i.item_type == "squad")
+        .flat_map(|i| squads.token_id().filter(&i.item_id))
+        .filter(|s| s.owner_fid == fid && s.active)
+        .map(|s| s.intelligence_score)
+        .max()
+        .or_else(|| ctx.db().npc_registry().npc_fid().find(fid).map(|n| 40 + n.difficulty_tier as i32 * 10))
+        .unwrap_or(50)
+        .clamp(0, 100);
+    sim::SimTeam { squad_rating, players }
+}
+
+fn sim_officials_for(ctx: &ReducerContext, match_id: &str) -> sim::SimOfficials {
+    let Some(crew) = ctx.db().match_official_assignment().match_id().find(match_id.to_string()) else {
+        return sim::SimOfficials::default();
+    };
+    let officials = ctx.db().officials();
+    sim::SimOfficials {
+        referee: officials.official_id().find(&crew.referee_id),
+        assistant_left: officials.official_id().find(&crew.assistant_left_id),
+        assistant_right: officials.official_id().find(&crew.assistant_right_id),
+        var: crew.var_id.and_then(|id| officials.official_id().find(&id)),
+    }
+}
+
+/// Runs the simulator for a match. The challenger plays at home.
+/// Simulates `home_fid` against `away_fid`; `match_id` seeds the run and
+/// selects any assigned officials.
+fn simulate_between(ctx: &ReducerContext, match_id: &str, home_fid: i64, away_fid: i64) -> sim::SimResult {
+    let seed = sim::seed_for(match_id, ctx.rng().gen::<u64>());
+    let home = sim_team_for(ctx, home_fid);
+    let away = sim_team_for(ctx, away_fid);
+    sim::simulate(seed, &home, &away, &sim_officials_for(ctx, match_id))
+}
+
+fn simulate_pvp_match(ctx: &ReducerContext, m: &PvpMatch) -> sim::SimResult {
+    simulate_between(ctx, &m.id, m.challenger_fid, m.challenged_fid)
+}
+
+/// Folds a simulated result into the PlayerState of everyone who took part.
+fn apply_sim_to_players(ctx: &ReducerContext, home_fid: i64, away_fid: i64, res: &sim::SimResult) -> Result<(), ReducerError> {
+    let outcome = |mine: i64, theirs: i64| if mine > theirs { "win" } else if mine < theirs { "loss" } else { "draw" };
+    for (fid, result) in [(home_fid, outcome(res.home, res.away)), (away_fid, outcome(res.away, res.home))] {
+        for p in sim_team_for(ctx, fid).players {
+            let minutes = res.sent_off.get(&p.player_id).copied().unwrap_or(90);
+            let events = res.stats.get(&p.player_id).cloned().unwrap_or_default();
+            apply_match_to_player(ctx, &p.player_id, minutes, false, result, &events)?;
+        }
+    }
+    Ok(())
+}
+
+/// Plays an accepted match on the server and finalizes it with the simulated
+/// result, so the scoreline cannot be chosen by either side.
+#[reducer]
+pub fn pvp_simulate_match(ctx: &ReducerContext, match_id: String, requester_fid: i64) -> Result<(), ReducerError> {
+    let requester_fid = resolve_actor(ctx, requester_fid)?;
+    let m = ctx.db().pvp_match().id().find(&match_id).ok_or(ReducerError::MatchNotFound)?;
+    if m.status != "active" { return Err(ReducerError::InvalidState); }
+    if requester_fid != m.challenger_fid && requester_fid != m.challenged_fid && !is_trusted_service(ctx) { return Err(ReducerError::NotParticipant); }
+
+    play_pvp_sim(ctx, m, requester_fid)
+}
+
+fn play_pvp_sim(ctx: &ReducerContext, m: PvpMatch, actor_fid: i64) -> Result<(), ReducerError> {
+    let res = simulate_pvp_match(ctx, &m);
+    // Player states are read when picking the lineups, so update them only afterwards
+    apply_sim_to_players(ctx, m.challenger_fid, m.challenged_fid, &res)?;
+    let result_json = serde_json::to_string(&res).unwrap_or_else(|_| "{}".into());
+    append_event(ctx, "pvp_match_simulated", actor_fid, result_json.clone(), Some(m.id.clone()));
+    finalize_pvp_match(ctx, m, result_json, "sim", actor_fid)
+}
+
+// --- Leagues ---
+
+/// Creates a division of `pyramid` with its starting managers. NPC managers
+/// (fids in npc_registry) always have their fixtures simulated.
+#[reducer]
+pub fn league_create(ctx: &ReducerContext, pyramid: String, name: String, division: u32, promotion_slots: u32, relegation_slots: u32, member_fids: Vec<i64>) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend"])?;
+    if pyramid.trim().is_empty() || name.trim().is_empty() || division == 0 { return Err(ReducerError::InvalidPayload); }
+    if !(LEAGUE_MIN_MEMBERS..=LEAGUE_MAX_MEMBERS).contains(&member_fids.len()) { return Err(ReducerError::OutOfRange); }
+    let mut unique = member_fids.clone();
+    unique.sort_unstable();
+    unique.dedup();
+    if unique.len() != member_fids.len() { return Err(ReducerError::InvalidPayload); }
+    if (promotion_slots.max(relegation_slots) as usize) * 2 > member_fids.len() { return Err(ReducerError::OutOfRange); }
+    let leagues = ctx.db().league();
+    let divisions: Vec<League> = leagues.pyramid().filter(&pyramid).collect();
+    if divisions.iter().any(|l| l.division == division) { return Err(ReducerError::LeagueExists); }
+    // Managers swapped between adjacent divisions must balance, or sizes drift each season
+    let above = divisions.iter().find(|l| l.division + 1 == division);
+    let below = divisions.iter().find(|l| l.division == division + 1);
+    if above.is_some_and(|l| l.relegation_slots != promotion_slots) || below.is_some_and(|l| l.promotion_slots != relegation_slots) {
+        return Err(ReducerError::PromotionMismatch);
+    }
+    if ctx.db().season().pyramid().filter(&pyramid).any(|s| s.status == "active") { return Err(ReducerError::SeasonActive); }
+    let members = ctx.db().league_member();
+    if member_fids.iter().any(|fid| members.fid().find(fid).is_some()) { return Err(ReducerError::AlreadyInLeague); }
+
+    let now = now_ms(ctx);
+    let id = new_id(ctx, "league", &format!("{}:{}", pyramid, division));
+    leagues.insert(League { id: id.clone(), pyramid, name, division, promotion_slots, relegation_slots, created_at_ms: now });
+    for fid in member_fids {
+        let is_npc = ctx.db().npc_registry().npc_fid().find(fid).is_some();
+        members.insert(LeagueMember { fid, league_id: id.clone(), is_npc, joined_at_ms: now });
+    }
+    append_event(ctx, "league_created", 0, "{}".into(), Some(id));
+    Ok(())
+}
+
+/// Starts the next season of `pyramid`: a double round-robin in every
+/// division, one round every `round_interval_hours` from `first_kickoff_ms`.
+#[reducer]
+pub fn season_start(ctx: &ReducerContext, pyramid: String, mode: String, round_interval_hours: i64, first_kickoff_ms: i64) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend"])?;
+    if !matches!(mode.as_str(), "sim" | "played") { return Err(ReducerError::InvalidPayload); }
+    if !(1..=LEAGUE_MAX_ROUND_INTERVAL_HOURS).contains(&round_interval_hours) { return Err(ReducerError::OutOfRange); }
+    let seasons = ctx.db().season();
+    let previous: Vec<Season> = seasons.pyramid().filter(&pyramid).collect();
+    if previous.iter().any(|s| s.status == "active") { return Err(ReducerError::SeasonActive); }
+    let leagues: Vec<League> = ctx.db().league().pyramid().filter(&pyramid).collect();
+    if leagues.is_empty() { return Err(ReducerError::LeagueNotFound); }
+
+    let now = now_ms(ctx);
+    let number = previous.iter().map(|s| s.number).max().unwrap_or(0) + 1;
+    let season_id = new_id(ctx, "season", &format!("{}:{}", pyramid, number));
+    let first_kickoff_ms = first_kickoff_ms.max(now);
+    for league in &leagues {
+        let mut fids: Vec<i64> = ctx.db().league_member().league_id().filter(&league.id).map(|m| m.fid).collect();
+        fids.sort_unstable();
+        for &fid in &fids {
+            ctx.db().standing().insert(Standing {
+                id: format!("{}:{}", season_id, fid),
+                season_id: season_id.clone(),
+                league_id: league.id.clone(),
+                fid,
+                played: 0, won: 0, drawn: 0, lost: 0, goals_for: 0, goals_against: 0, points: 0,
+                form: String::new(),
+            });
+            push_inbox(ctx, fid, format!("season-start-{}-{}", season_id, fid), "season_started", "Season Kicks Off", &format!("{} season {} has started.", league.name, number));
+        }
+        for (round, pairs) in competition::round_robin(&fids).into_iter().enumerate() {
+            let kickoff_at_ms = first_kickoff_ms + round as i64 * round_interval_hours * 60 * 60 * 1000;
+            for (home_fid, away_fid) in pairs {
+                let id = new_id(ctx, "fixture", &format!("{}:{}:{}", season_id, home_fid, away_fid));
+                ctx.db().fixture().insert(Fixture {
+                    id: id.clone(),
+                    season_id: season_id.clone(),
+                    league_id: league.id.clone(),
+                    round: round as u32 + 1,
+                    home_fid,
+                    away_fid,
+                    kickoff_at_ms,
+                    status: "scheduled".into(),
+                    match_id: String::new(),
+                    home_goals: None,
+                    away_goals: None,
+                    finished_at_ms: None,
+                });
+                ctx.db().fixture_kickoff().insert(FixtureKickoff {
+                    scheduled_id: 0,
+                    scheduled_at: Timestamp::from_micros_since_unix_epoch(kickoff_at_ms * 1000).into(),
+                    fixture_id: id,
+                });
+            }
+        }
+    }
+    seasons.insert(Season { id: season_id.clone(), pyramid, number, status: "active".into(), mode, round_interval_hours, started_at_ms: now, finished_at_ms: None });
+    append_event(ctx, "season_started", 0, format!("{{\"number\":{}}}", number), Some(season_id));
+    Ok(())
+}
+
+/// Scheduled at each fixture's kickoff. Human pairings in a "played" season
+/// get a PvP match (simulated if abandoned); everything else is simulated now.
+#[reducer]
+pub fn fixture_kickoff_tick(ctx: &ReducerContext, timer: FixtureKickoff) -> Result<(), ReducerError> {
+    if ctx.sender != ctx.identity() { return Err(ReducerError::SchedulerOnly); }
+    let Some(mut f) = ctx.db().fixture().id().find(&timer.fixture_id) else { return Ok(()); };
+    if f.status != "scheduled" { return Ok(()); }
+    let Some(season) = ctx.db().season().id().find(&f.season_id).filter(|s| s.status == "active") else { return Ok(()); };
+    let members = ctx.db().league_member();
+    let is_human = |fid: i64| members.fid().find(fid).is_some_and(|m| !m.is_npc);
+    if season.mode == "played" && is_human(f.home_fid) && is_human(f.away_fid) {
+        f.match_id = create_matched_pvp(ctx, f.home_fid, f.away_fid, "league");
+        f.status = "in_play".into();
+        ctx.db().fixture().id().update(f);
+        return Ok(());
+    }
+    play_fixture_sim(ctx, f)
+}
+
+fn play_fixture_sim(ctx: &ReducerContext, f: Fixture) -> Result<(), ReducerError> {
+    let res = simulate_between(ctx, &f.id, f.home_fid, f.away_fid);
+    apply_sim_to_players(ctx, f.home_fid, f.away_fid, &res)?;
+    append_event(ctx, "fixture_simulated", 0, serde_json::to_string(&res).unwrap_or_else(|_| "{}".into()), Some(f.id.clone()));
+    record_fixture_result(ctx, f, res.home, res.away)
+}
+
+fn update_standing(ctx: &ReducerContext, season_id: &str, fid: i64, goals_for: i64, goals_against: i64) {
+    let tbl = ctx.db().standing();
+    let Some(mut s) = tbl.id().find(format!("{}:{}", season_id, fid)) else { return; };
+    s.played += 1;
+    match goals_for.cmp(&goals_against) {
+        std::cmp::Ordering::Greater => { s.won += 1; s.points += 3; }
+        std::cmp::Ordering::Equal => { s.drawn += 1; s.points += 1; }
+        std::cmp::Ordering::Less => s.lost += 1,
+    }
+    s.goals_for += goals_for;
+    s.goals_against += goals_against;
+    s.form = competition::push_form(&s.form, goals_for, goals_against);
+    tbl.id().update(s);
+}
+
+/// Writes a fixture's score into the standings and closes the season once
+/// its last fixture is in.
+fn record_fixture_result(ctx: &ReducerContext, mut f: Fixture, home: i64, away: i64) -> Result<(), ReducerError> {
+    f.status = "finished".into();
+    f.home_goals = Some(home);
+    f.away_goals = Some(away);
+    f.finished_at_ms = Some(now_ms(ctx));
+    ctx.db().fixture().id().update(f.clone());
+    update_standing(ctx, &f.season_id, f.home_fid, home, away);
+    update_standing(ctx, &f.season_id, f.away_fid, away, home);
+    append_event(ctx, "fixture_result", 0, format!("{{\"home\":{},\"away\":{}}}", home, away), Some(f.id.clone()));
+    if ctx.db().fixture().season_id().filter(&f.season_id).all(|x| x.status == "finished") {
+        finish_season(ctx, &f.season_id);
+    }
+    Ok(())
+}
+
+/// Closes a season: crowns each division's winner, then swaps the bottom of
+/// every division with the top of the one below.
+fn finish_season(ctx: &ReducerContext, season_id: &str) {
+    let seasons = ctx.db().season();
+    let Some(mut season) = seasons.id().find(season_id.to_string()) else { return; };
+    season.status = "finished".into();
+    season.finished_at_ms = Some(now_ms(ctx));
+    seasons.id().update(season.clone());
+
+    let mut leagues: Vec<League> = ctx.db().league().pyramid().filter(&season.pyramid).collect();
+    leagues.sort_by_key(|l| l.division);
+    let standings: Vec<Standing> = ctx.db().standing().season_id().filter(season_id).collect();
+    let table = |league_id: &str| {
+        let mut rows: Vec<Standing> = standings.iter().filter(|s| s.league_id == league_id).cloned().collect();
+        rows.sort_by(competition::table_order);
+        rows.into_iter().map(|s| s.fid).collect::<Vec<i64>>()
+    };
+    let tables: Vec<Vec<i64>> = leagues.iter().map(|l| table(&l.id)).collect();
+    for (league, fids) in leagues.iter().zip(&tables) {
+        if let Some(&champion) = fids.first() {
+            push_inbox(ctx, champion, format!("league-champion-{}-{}", season_id, champion), "league_champion", "Champions!", &format!("You won {} season {}.", league.name, season.number));
+        }
+    }
+
+    let members = ctx.db().league_member();
+    let move_to = |fid: i64, league: &League, kind: &str, title: &str| {
+        if let Some(mut m) = members.fid().find(fid) {
+            m.league_id = league.id.clone();
+            members.fid().update(m);
+        }
+        append_event(ctx, kind, fid, format!("{{\"league_id\":\"{}\"}}", league.id), Some(season_id.to_string()));
+        push_inbox(ctx, fid, format!("{}-{}-{}", kind, season_id, fid), kind, title, &format!("Next season you play in {}.", league.name));
+    };
+    for i in 1..leagues.len() {
+        let (upper, lower) = (&leagues[i - 1], &leagues[i]);
+        // Only adjacent divisions exchange managers
+        if lower.division != upper.division + 1 { continue; }
+        // Equal by construction; the min guards divisions created before that check
+        let swaps = upper.relegation_slots.min(lower.promotion_slots) as usize;
+        let relegated = tables[i - 1].iter().rev().take(swaps).copied();
+        let promoted = tables[i].iter().take(swaps).copied();
+        for fid in relegated.collect::<Vec<_>>() { move_to(fid, lower, "league_relegated", "Relegated"); }
+        for fid in promoted.collect::<Vec<_>>() { move_to(fid, upper, "league_promoted", "Promoted!"); }
+    }
+    append_event(ctx, "season_finished", 0, format!("{{\"number\":{}}}", season.number), Some(season_id.to_string()));
+}
+
+// --- Cups ---
+
+#[reducer]
+pub fn cup_create(ctx: &ReducerContext, name: String, entry_fee_wei: String, max_entrants: u32, group_stage: bool, prize_split_bps: Vec<u32>) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend"])?;
+    if name.trim().is_empty() { return Err(ReducerError::InvalidPayload); }
+    // A zero fee makes a free cup with no prizes
+    let entry_fee_wei = wei::parse(&entry_fee_wei)?;
+    if !(CUP_MIN_ENTRANTS..=CUP_MAX_ENTRANTS).contains(&max_entrants) { return Err(ReducerError::OutOfRange); }
+    let prize_split_bps = if prize_split_bps.is_empty() { CUP_DEFAULT_PRIZE_SPLIT_BPS.to_vec() } else { prize_split_bps };
+    if prize_split_bps.len() > 4 || prize_split_bps.iter().map(|&b| b as u64).sum::<u64>() != 10_000 { return Err(ReducerError::InvalidPrizeSplit); }
+    let id = new_id(ctx, "cup", &name);
+    ctx.db().cup().insert(Cup {
+        id: id.clone(),
+        name,
+        status: "registration".into(),
+        entry_fee_wei,
+        prize_pool_wei: u256::ZERO,
+        max_entrants,
+        group_stage,
+        prize_split_bps,
+        knockout_rounds: 0,
+        current_round: 0,
+        winner_fid: None,
+        created_at_ms: now_ms(ctx),
+        started_at_ms: None,
+        finished_at_ms: None,
+    });
+    append_event(ctx, "cup_created", 0, "{}".into(), Some(id));
+    Ok(())
+}
+
+/// Enters the cup, paying the entry fee from the manager's available balance.
+#[reducer]
+pub fn cup_enter(ctx: &ReducerContext, fid: i64, cup_id: String) -> Result<(), ReducerError> {
+    let fid = resolve_actor(ctx, fid)?;
+    let cups = ctx.db().cup();
+    let mut cup = cups.id().find(&cup_id).ok_or(ReducerError::CupNotFound)?;
+    if cup.status != "registration" { return Err(ReducerError::CupClosed); }
+    let entries = ctx.db().cup_entry();
+    let entry_id = format!("{}:{}", cup_id, fid);
+    if entries.id().find(&entry_id).is_some() { return Err(ReducerError::AlreadyEntered); }
+    if entries.cup_id().filter(&cup_id).count() as u32 >= cup.max_entrants { return Err(ReducerError::CupFull); }
+    if cup.entry_fee_wei > u256::ZERO {
+        debit_available(ctx, fid, cup.entry_fee_wei)?;
+        record_ledger(ctx, fid, "cup_entry", "debit", cup.entry_fee_wei, None, &cup_id);
+        cup.prize_pool_wei = wei::add(cup.prize_pool_wei, cup.entry_fee_wei)?;
+    }
+    entries.insert(CupEntry { id: entry_id, cup_id: cup_id.clone(), fid, seed: 0, group_name: String::new(), status: "active".into(), eliminated_round: None, entered_at_ms: now_ms(ctx) });
+    cups.id().update(cup.clone());
+    append_event(ctx, "cup_entered", fid, "{}".into(), Some(cup_id.clone()));
+    push_inbox(ctx, fid, format!("cup-entered-{}-{}", cup_id, fid), "cup_entered", "Cup Entry Confirmed", &format!("You are entered in {}.", cup.name));
+    Ok(())
+}
+
+/// Calls off a cup before the draw and refunds every entry fee.
+#[reducer]
+pub fn cup_cancel(ctx: &ReducerContext, cup_id: String) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend"])?;
+    let cups = ctx.db().cup();
+    let mut cup = cups.id().find(&cup_id).ok_or(ReducerError::CupNotFound)?;
+    if cup.status != "registration" { return Err(ReducerError::CupClosed); }
+    let entries: Vec<CupEntry> = ctx.db().cup_entry().cup_id().filter(&cup_id).collect();
+    for e in &entries {
+        if cup.entry_fee_wei > u256::ZERO {
+            credit_available(ctx, e.fid, cup.entry_fee_wei)?;
+            record_ledger(ctx, e.fid, "cup_refund", "credit", cup.entry_fee_wei, None, &cup_id);
+        }
+        push_inbox(ctx, e.fid, format!("cup-cancelled-{}-{}", cup_id, e.fid), "cup_cancelled", "Cup Cancelled", &format!("{} was cancelled and your entry fee was refunded.", cup.name));
+    }
+    cup.status = "cancelled".into();
+    cup.prize_pool_wei = u256::ZERO;
+    cup.finished_at_ms = Some(now_ms(ctx));
+    cups.id().update(cup);
+    append_event(ctx, "cup_cancelled", 0, "{}".into(), Some(cup_id));
+    Ok(())
+}
+
+/// Closes registration and makes the draw: entrants are seeded by ELO, then
+/// either snaked into groups or placed straight into the bracket.
+#[reducer]
+pub fn cup_start(ctx: &ReducerContext, cup_id: String) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend"])?;
+    let mut cup = ctx.db().cup().id().find(&cup_id).ok_or(ReducerError::CupNotFound)?;
+    if cup.status != "registration" { return Err(ReducerError::CupClosed); }
+    let mut entries: Vec<CupEntry> = ctx.db().cup_entry().cup_id().filter(&cup_id).collect();
+    let min_entrants = if cup.group_stage { CUP_GROUP_SIZE * 2 } else { CUP_MIN_ENTRANTS as usize };
+    if entries.len() < min_entrants { return Err(ReducerError::NotEnoughEntrants); }
+
+    let users = ctx.db().user();
+    let elo_of = |fid: i64| users.fid().find(fid).map(|u| u.elo).unwrap_or(ELO_START);
+    entries.sort_by(|a, b| elo_of(b.fid).cmp(&elo_of(a.fid)).then(a.fid.cmp(&b.fid)));
+    let groups = entries.len().div_ceil(CUP_GROUP_SIZE);
+    for (i, e) in entries.iter_mut().enumerate() {
+        e.seed = i as u32 + 1;
+        if cup.group_stage {
+            // Snake order keeps the groups balanced: A B C C B A A B C ...
+            let (row, col) = (i / groups, i % groups);
+            let group = if row % 2 == 0 { col } else { groups - 1 - col };
+            e.group_name = ((b'A' + group as u8) as char).to_string();
+        }
+        ctx.db().cup_entry().id().update(e.clone());
+    }
+    cup.started_at_ms = Some(now_ms(ctx));
+    append_event(ctx, "cup_started", 0, format!("{{\"entrants\":{}}}", entries.len()), Some(cup_id.clone()));
+
+    if !cup.group_stage {
+        let seeded: Vec<i64> = entries.iter().map(|e| e.fid).collect();
+        return draw_knockout(ctx, cup, &seeded);
+    }
+    cup.status = "group_stage".into();
+    ctx.db().cup().id().update(cup.clone());
+    for g in 0..groups {
+        let name = ((b'A' + g as u8) as char).to_string();
+        let fids: Vec<i64> = entries.iter().filter(|e| e.group_name == name).map(|e| e.fid).collect();
+        // Single round-robin: the first half of the double round-robin
+        let rounds = competition::round_robin(&fids);
+        for (round, pairs) in rounds.iter().take(rounds.len() / 2).enumerate() {
+            for (slot, &(home, away)) in pairs.iter().enumerate() {
+                open_cup_tie(ctx, &cup, "group", round as u32 + 1, slot as u32, &name, home, Some(away));
+            }
+        }
+        for &fid in &fids {
+            push_inbox(ctx, fid, format!("cup-group-{}-{}", cup_id, fid), "cup_group_drawn", "Cup Draw", &format!("You were drawn in group {} of {}.", name, cup.name));
+        }
+    }
+    Ok(())
+}
+
+/// Places `seeded` (strongest first) into a power-of-two bracket. Missing
+/// entrants become byes, which always fall to the top seeds.
+fn draw_knockout(ctx: &ReducerContext, mut cup: Cup, seeded: &[i64]) -> Result<(), ReducerError> {
+    let size = seeded.len().next_power_of_two();
+    cup.knockout_rounds = size.trailing_zeros();
+    cup.current_round = 1;
+    cup.status = "knockout".into();
+    ctx.db().cup().id().update(cup.clone());
+    let order = competition::bracket_seeds(size);
+    for (slot, pair) in order.chunks(2).enumerate() {
+        let home = seeded[pair[0] - 1];
+        let away = seeded.get(pair[1] - 1).copied();
+        open_cup_tie(ctx, &cup, "knockout", 1, slot as u32, "", home, away);
+    }
+    append_event(ctx, "cup_round_started", 0, "{\"round\":1}".into(), Some(cup.id.clone()));
+    Ok(())
+}
+
+/// Creates a tie and its PvP match; a bye is settled on the spot.
+#[allow(clippy::too_many_arguments)]
+fn open_cup_tie(ctx: &ReducerContext, cup: &Cup, stage: &str, round: u32, slot: u32, group_name: &str, home_fid: i64, away_fid: Option<i64>) {
+    let id = new_id(ctx, "cuptie", &format!("{}:{}:{}:{}:{}", cup.id, stage, round, slot, group_name));
+    let mut tie = CupTie {
+        id,
+        cup_id: cup.id.clone(),
+        stage: stage.into(),
+        round,
+        slot,
+        group_name: group_name.into(),
+        home_fid,
+        away_fid,
+        status: "in_play".into(),
+        match_id: String::new(),
+        home_goals: None,
+        away_goals: None,
+        penalties_json: None,
+        decided_by: String::new(),
+        winner_fid: None,
+        finished_at_ms: None,
+    };
+    match away_fid {
+        Some(away) => tie.match_id = create_matched_pvp(ctx, home_fid, away, "cup"),
+        None => {
+            tie.status = "finished".into();
+            tie.decided_by = "bye".into();
+            tie.winner_fid = Some(home_fid);
+            tie.finished_at_ms = Some(now_ms(ctx));
+            push_inbox(ctx, home_fid, format!("cup-bye-{}-{}-{}", cup.id, round, home_fid), "cup_bye", "Cup Bye", &format!("You have a bye through round {} of {}.", round, cup.name));
+        }
+    }
+    ctx.db().cup_tie().insert(tie);
+}
+
+fn play_cup_tie_sim(ctx: &ReducerContext, tie: CupTie) -> Result<(), ReducerError> {
+    let Some(away_fid) = tie.away_fid else { return Ok(()); };
+    let res = simulate_between(ctx, &tie.id, tie.home_fid, away_fid);
+    apply_sim_to_players(ctx, tie.home_fid, away_fid, &res)?;
+    append_event(ctx, "cup_tie_simulated", 0, serde_json::to_string(&res).unwrap_or_else(|_| "{}".into()), Some(tie.id.clone()));
+    record_cup_tie(ctx, tie, res.home, res.away)
+}
+
+/// Records a tie's score. A level knockout tie goes to extra time and then
+/// penalties, both simulated on the server.
+fn record_cup_tie(ctx: &ReducerContext, mut tie: CupTie, home: i64, away: i64) -> Result<(), ReducerError> {
+    let Some(away_fid) = tie.away_fid else { return Ok(()); };
+    let (mut home_goals, mut away_goals) = (home, away);
+    tie.decided_by = "normal".into();
+    if tie.stage == "knockout" && home == away {
+        let seed = sim::seed_for(&tie.id, ctx.rng().gen::<u64>());
+        let decider = sim::extra_time_and_penalties(seed, &sim_team_for(ctx, tie.home_fid), &sim_team_for(ctx, away_fid));
+        home_goals += decider.extra_time.0;
+        away_goals += decider.extra_time.1;
+        tie.decided_by = "extra_time".into();
+        if let Some((ph, pa)) = decider.penalties {
+            tie.decided_by = "penalties".into();
+            tie.penalties_json = Some(format!("{{\"home\":{},\"away\":{}}}", ph, pa));
+            tie.winner_fid = Some(if ph > pa { tie.home_fid } else { away_fid });
+        }
+    }
+    if tie.winner_fid.is_none() && home_goals != away_goals {
+        tie.winner_fid = Some(if home_goals > away_goals { tie.home_fid } else { away_fid });
+    }
+    tie.home_goals = Some(home_goals);
+    tie.away_goals = Some(away_goals);
+    tie.status = "finished".into();
+    tie.finished_at_ms = Some(now_ms(ctx));
+    ctx.db().cup_tie().id().update(tie.clone());
+    let payload = format!(
+        "{{\"home\":{},\"away\":{},\"decided_by\":\"{}\",\"penalties\":{}}}",
+        home_goals, away_goals, tie.decided_by, tie.penalties_json.as_deref().unwrap_or("null"),
+    );
+    append_event(ctx, "cup_tie_result", tie.winner_fid.unwrap_or(0), payload, Some(tie.id.clone()));
+    advance_cup(ctx, &tie.cup_id)
+}
+
+fn eliminate_cup_entry(ctx: &ReducerContext, cup: &Cup, fid: i64, round: u32) {
+    let entries = ctx.db().cup_entry();
+    if let Some(mut e) = entries.id().find(format!("{}:{}", cup.id, fid)) {
+        e.status = "eliminated".into();
+        e.eliminated_round = Some(round);
+        entries.id().update(e);
+    }
+    let stage = if round == 0 { "the group stage".to_string() } else { format!("round {}", round) };
+    push_inbox(ctx, fid, format!("cup-out-{}-{}", cup.id, fid), "cup_eliminated", "Knocked Out", &format!("You went out of {} in {}.", cup.name, stage));
+}
+
+/// Moves the cup on once every tie of the current stage is finished: group
+/// tables feed the bracket, and knockout winners are paired in slot order.
+fn advance_cup(ctx: &ReducerContext, cup_id: &str) -> Result<(), ReducerError> {
+    let Some(mut cup) = ctx.db().cup().id().find(cup_id.to_string()) else { return Ok(()); };
+    let ties: Vec<CupTie> = ctx.db().cup_tie().cup_id().filter(cup_id).collect();
+    match cup.status.as_str() {
+        "group_stage" => {
+            if ties.iter().any(|t| t.status != "finished") { return Ok(()); }
+            let entries: Vec<CupEntry> = ctx.db().cup_entry().cup_id().filter(cup_id).collect();
+            let mut group_names: Vec<String> = entries.iter().map(|e| e.group_name.clone()).collect();
+            group_names.sort();
+            group_names.dedup();
+            // Advancing sides by finishing place: every group winner, then every runner-up
+            let mut by_place: Vec<Vec<(i64, usize)>> = vec![Vec::new(); CUP_GROUP_ADVANCE];
+            for (group, name) in group_names.iter().enumerate() {
+                let mut table: Vec<Standing> = entries.iter().filter(|e| &e.group_name == name).map(|e| Standing {
+                    id: e.id.clone(), season_id: String::new(), league_id: String::new(), fid: e.fid,
+                    played: 0, won: 0, drawn: 0, lost: 0, goals_for: 0, goals_against: 0, points: 0, form: String::new(),
+                }).collect();
+                for t in ties.iter().filter(|t| &t.group_name == name) {
+                    let (Some(away_fid), Some(hg), Some(ag)) = (t.away_fid, t.home_goals, t.away_goals) else { continue; };
+                    for row in table.iter_mut() {
+                        let (gf, ga) = if row.fid == t.home_fid { (hg, ag) } else if row.fid == away_fid { (ag, hg) } else { continue; };
+                        row.goals_for += gf;
+                        row.goals_against += ga;
+                        row.points += match gf.cmp(&ga) { std::cmp::Ordering::Greater => 3, std::cmp::Ordering::Equal => 1, std::cmp::Ordering::Less => 0 };
+                    }
+                }
+                table.sort_by(competition::table_order);
+                for (place, row) in table.iter().enumerate() {
+                    if place < CUP_GROUP_ADVANCE { by_place[place].push((row.fid, group)); } else { eliminate_cup_entry(ctx, &cup, row.fid, 0); }
+                }
+            }
+            // Group mates must not meet again in the first knockout round
+            let seeded = competition::cross_group_seeds(&by_place);
+            draw_knockout(ctx, cup, &seeded)
+        }
+        "knockout" => {
+            let mut round: Vec<&CupTie> = ties.iter().filter(|t| t.stage == "knockout" && t.round == cup.current_round).collect();
+            if round.iter().any(|t| t.status != "finished") { return Ok(()); }
+            round.sort_by_key(|t| t.slot);
+            let winners: Vec<i64> = round.iter().filter_map(|t| t.winner_fid).collect();
+            for t in &round {
+                let loser = if t.winner_fid == Some(t.home_fid) { t.away_fid } else { Some(t.home_fid) };
+                if let Some(fid) = loser { eliminate_cup_entry(ctx, &cup, fid, cup.current_round); }
+            }
+            if winners.len() <= 1 {
+                return match winners.first() {
+                    Some(&champion) => finish_cup(ctx, cup, champion),
+                    None => Ok(()),
+                };
+            }
+            cup.current_round += 1;
+            ctx.db().cup().id().update(cup.clone());
+            for (slot, pair) in winners.chunks(2).enumerate() {
+                open_cup_tie(ctx, &cup, "knockout", cup.current_round, slot as u32, "", pair[0], pair.get(1).copied());
+            }
+            for &fid in &winners {
+                push_inbox(ctx, fid, format!("cup-through-{}-{}-{}", cup.id, cup.current_round, fid), "cup_advanced", "Through to the Next Round", &format!("You reached round {} of {}.", cup.current_round, cup.name));
+            }
+            append_event(ctx, "cup_round_started", 0, format!("{{\"round\":{}}}", cup.current_round), Some(cup.id.clone()));
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Crowns the champion and pays out the pool by finishing place. Shares for
+/// places the bracket never produced, and rounding dust, go to the champion.
+fn finish_cup(ctx: &ReducerContext, mut cup: Cup, champion: i64) -> Result<(), ReducerError> {
+    cup.status = "finished".into();
+    cup.winner_fid = Some(champion);
+    cup.finished_at_ms = Some(now_ms(ctx));
+    ctx.db().cup().id().update(cup.clone());
+    let entries: Vec<CupEntry> = ctx.db().cup_entry().cup_id().filter(&cup.id).collect();
+    if let Some(mut e) = entries.iter().find(|e| e.fid == champion).cloned() {
+        e.status = "champion".into();
+        ctx.db().cup_entry().id().update(e);
+    }
+
+    let mut payouts: Vec<(i64, u256)> = Vec::new();
+    let mut paid = u256::ZERO;
+    for (place, &bps) in cup.prize_split_bps.iter().enumerate().skip(1) {
+        // Place 1 is the runner-up (lost the final), 2 the semi-finalists, ...
+        let Some(round) = (cup.knockout_rounds + 1).checked_sub(place as u32).filter(|r| *r >= 1) else { continue; };
+        let winners: Vec<i64> = entries.iter().filter(|e| e.eliminated_round == Some(round)).map(|e| e.fid).collect();
+        if winners.is_empty() || bps == 0 { continue; }
+        let share = cup.prize_pool_wei.checked_mul(u256::new(bps as u128)).ok_or(ReducerError::AmountOverflow)? / u256::new(10_000) / u256::new(winners.len() as u128);
+        for fid in winners {
+            payouts.push((fid, share));
+            paid = wei::add(paid, share)?;
+        }
+    }
+    payouts.insert(0, (champion, wei::sub(cup.prize_pool_wei, paid, ReducerError::AmountOverflow)?));
+    for (fid, amount) in payouts {
+        if amount > u256::ZERO {
+            credit_available(ctx, fid, amount)?;
+            record_ledger(ctx, fid, "cup_prize", "credit", amount, None, &cup.id);
+        }
+        let (title, body) = if fid == champion {
+            ("Cup Winners!", format!("You won {}.", cup.name))
+        } else {
+            ("Cup Prize", format!("You earned prize money in {}.", cup.name))
+        };
+        push_inbox(ctx, fid, format!("cup-prize-{}-{}", cup.id, fid), "cup_prize", title, &body);
+    }
+    append_event(ctx, "cup_finished", champion, format!("{{\"prize_pool_wei\":\"{}\"}}", cup.prize_pool_wei), Some(cup.id.clone()));
+    Ok(())
+}
+
+// --- Matchmaking ---
+
+fn is_npc_fid(ctx: &ReducerContext, fid: i64) -> bool {
+    ctx.db().npc_registry().npc_fid().find(fid).is_some()
+}
+
+/// True while the manager has a match that is not yet finalized.
+fn has_open_pvp(ctx: &ReducerContext, fid: i64) -> bool {
+    let tbl = ctx.db().pvp_match();
+    let open = |m: &PvpMatch| matches!(m.status.as_str(), "pending" | "active" | "awaiting_confirmation" | "disputed");
+    tbl.pair_status().filter(fid).any(|m| open(&m)) || tbl.challenged_fid().filter(fid).any(|m| open(&m))
+}
+
+fn mm_window(waited_ms: i64) -> i32 {
+    let steps = (waited_ms / (MM_WINDOW_STEP_SECONDS * 1000)).min(i32::MAX as i64) as i32;
+    MM_BASE_WINDOW.saturating_add(steps.saturating_mul(MM_WINDOW_STEP)).min(MM_MAX_WINDOW)
+}
+
+/// NPC difficulty tier (1..5) that corresponds to an ELO, centred on ELO_START.
+fn elo_to_npc_tier(elo: i32) -> i16 {
+    (3 + (elo - ELO_START) / 150).clamp(1, 5) as i16
+}
+
+/// Creates an already-accepted match for a queued pairing and notifies both sides.
+fn create_matched_pvp(ctx: &ReducerContext, challenger_fid: i64, challenged_fid: i64, source: &str) -> String {
+    let now = now_ms(ctx);
+    let id = new_id(ctx, "pvp", &format!("{}:{}", challenger_fid, challenged_fid));
+    ctx.db().pvp_match().insert(PvpMatch {
+        id: id.clone(),
+        challenger_fid,
+        challenged_fid,
+        status: "active".into(),
+        created_at_ms: now,
+        accepted_at_ms: Some(now),
+        result_json: None,
+        reported_by_fid: None,
+        reported_at_ms: None,
+        finalized_at_ms: None,
+    });
+    schedule_pvp_timeout(ctx, &id, "abandon", load_pvp_config(ctx).active_deadline_hours);
+    append_event(ctx, "pvp_match_created", challenger_fid, format!("{{\"source\":\"{}\"}}", source), Some(id.clone()));
+    for (fid, opponent) in [(challenger_fid, challenged_fid), (challenged_fid, challenger_fid)] {
+        push_inbox(ctx, fid, format!("pvp-matched-{}-{}", id, fid), "pvp_matched", "Match Found", &format!("You were matched against FID {}.", opponent));
+    }
+    id
+}
+
+#[reducer]
+pub fn matchmaking_enqueue(ctx: &ReducerContext, fid: i64) -> Result<(), ReducerError> {
+    let fid = resolve_actor(ctx, fid)?;
+    let queue = ctx.db().matchmaking_queue();
+    if queue.fid().find(fid).is_some() { return Err(ReducerError::AlreadyQueued); }
+    if has_open_pvp(ctx, fid) { return Err(ReducerError::InvalidState); }
+    let elo = ctx.db().user().fid().find(fid).map(|u| u.elo).unwrap_or(ELO_START);
+    queue.insert(MatchmakingQueue { fid, elo, enqueued_at_ms: now_ms(ctx) });
+    if ctx.db().matchmaking_schedule().count() == 0 {
+        let every = std::time::Duration::from_secs(MM_TICK_SECONDS);
+        ctx.db().matchmaking_schedule().insert(MatchmakingSchedule { scheduled_id: 0, scheduled_at: every.into() });
+    }
+    append_event(ctx, "matchmaking_enqueued", fid, format!("{{\"elo\":{}}}", elo), None);
+    Ok(())
+}
+
+#[reducer]
+pub fn matchmaking_leave(ctx: &ReducerContext, fid: i64) -> Result<(), ReducerError> {
+    let fid = resolve_actor(ctx, fid)?;
+    if !ctx.db().matchmaking_queue().fid().delete(fid) { return Err(ReducerError::NotQueued); }
+    append_event(ctx, "matchmaking_left", fid, "{}".into(), None);
+    Ok(())
+}
+
+/// Scheduled: pairs queued managers oldest-first with the closest ELO inside
+/// both of their windows; anyone left waiting past MM_NPC_FALLBACK_SECONDS gets
+/// an active NPC of matching difficulty instead.
+#[reducer]
+pub fn matchmaking_tick(ctx: &ReducerContext, schedule: MatchmakingSchedule) -> Result<(), ReducerError> {
+    if ctx.sender != ctx.identity() { return Err(ReducerError::SchedulerOnly); }
+    let now = now_ms(ctx);
+    let queue = ctx.db().matchmaking_queue();
+    // Entrants who got into a match some other way (e.g. a direct challenge) leave the queue
+    let (busy, mut waiting): (Vec<MatchmakingQueue>, Vec<MatchmakingQueue>) = queue.iter().partition(|q| has_open_pvp(ctx, q.fid));
+    for q in busy {
+        queue.fid().delete(q.fid);
+    }
+    waiting.sort_by(|a, b| a.enqueued_at_ms.cmp(&b.enqueued_at_ms).then(a.fid.cmp(&b.fid)));
+
+    let mut matched: Vec<i64> = Vec::new();
+    for (i, a) in waiting.iter().enumerate() {
+        if matched.contains(&a.fid) { continue; }
+        let window_a = mm_window(now - a.enqueued_at_ms);
+        let partner = waiting[i + 1..].iter()
+            .filter(|b| !matched.contains(&b.fid))
+            .filter(|b| (a.elo - b.elo).abs() <= window_a.min(mm_window(now - b.enqueued_at_ms)))
+            .min_by_key(|b| ((a.elo - b.elo).abs(), b.enqueued_at_ms, b.fid));
+        if let Some(b) = partner {
+            matched.extend([a.fid, b.fid]);
+            create_matched_pvp(ctx, a.fid, b.fid, "matchmaking");
+            continue;
+        }
+        if now - a.enqueued_at_ms < MM_NPC_FALLBACK_SECONDS * 1000 { continue; }
+        if let Some(n) = nearest_free_npc(ctx, a.fid, a.elo) {
+            matched.push(a.fid);
+            // NPCs never report or confirm, so the module plays the match itself
+            let match_id = create_matched_pvp(ctx, a.fid, n.npc_fid, "matchmaking_npc");
+            let m = ctx.db().pvp_match().id().find(&match_id).ok_or(ReducerError::MatchNotFound)?;
+            play_pvp_sim(ctx, m, a.fid)?;
+        }
+    }
+    for fid in matched {
+        queue.fid().delete(fid);
+    }
+    // Stop ticking once the queue drains; the next enqueue re-arms the schedule
+    if queue.count() == 0 {
+        ctx.db().matchmaking_schedule().scheduled_id().delete(schedule.scheduled_id);
+    }
+    Ok(())
+}
+
+/// Picks an active NPC without an open match, searching tier buckets outward from
+/// the one matching `elo` and preferring the closest rating within a bucket.
+fn nearest_free_npc(ctx: &ReducerContext, fid: i64, elo: i32) -> Option<NpcRegistry> {
+    let tier = elo_to_npc_tier(elo);
+    let users = ctx.db().user();
+    let registry = ctx.db().npc_registry();
+    (0..5i16).find_map(|d| {
+        let tiers = if d == 0 { vec![tier] } else { vec![tier - d, tier + d] };
+        tiers.into_iter()
+            .filter(|t| (1..=5).contains(t))
+            .flat_map(|t| registry.difficulty_tier().filter(t))
+            .filter(|n| n.active && n.npc_fid != fid && !has_open_pvp(ctx, n.npc_fid))
+            .min_by_key(|n| {
+                let npc_elo = users.fid().find(n.npc_fid).map(|u| u.elo).unwrap_or(ELO_START);
+                ((npc_elo - elo).abs(), n.npc_fid)
+            })
+    })
+}
 
 // --- Officials & Commentary Reducers (stubs) ---
 
 #[reducer]
+#[allow(clippy::too_many_arguments)]
 pub fn official_create(
     ctx: &ReducerContext,
     role: String,
@@ -852,9 +3487,9 @@ pub fn official_create(
     consistency: i32,
     fitness: i32,
     reputation: i32,
-) {
+) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend", "moderator"])?;
     let id = new_id(ctx, "off", &format!("{}:{}", role, ai_seed));
-    let now = now_ms(ctx);
     ctx.db().officials().insert(Official {
         official_id: id,
         role,
@@ -869,6 +3504,7 @@ pub fn official_create(
         active: true,
         last_assigned_ms: 0,
     });
+    Ok(())
 }
 
 #[reducer]
@@ -879,7 +3515,8 @@ pub fn official_assign_to_match(
     assistant_left_id: String,
     assistant_right_id: String,
     var_id: Option<String>,
-) {
+) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend", "moderator"])?;
     let now = now_ms(ctx);
     let tbl = ctx.db().match_official_assignment();
     match tbl.match_id().find(&match_id) {
@@ -910,6 +3547,7 @@ pub fn official_assign_to_match(
     if let Some(vid) = var_id.as_ref() {
         if let Some(mut o) = off_tbl.official_id().find(vid) { o.last_assigned_ms = now; off_tbl.official_id().update(o); }
     }
+    Ok(())
 }
 
 #[reducer]
@@ -919,15 +3557,17 @@ pub fn official_update_after_match(
     fitness_delta: i32,
     reputation_delta: i32,
     consistency_delta: i32,
-) {
+) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend", "moderator"])?;
     let tbl = ctx.db().officials();
     if let Some(mut o) = tbl.official_id().find(&official_id) {
-        let clamp = |v: i32| -> i32 { if v < 0 { 0 } else if v > 100 { 100 } else { v } };
+        let clamp = |v: i32| -> i32 { v.clamp(0, 100) };
         o.fitness = clamp(o.fitness + fitness_delta);
         o.reputation = clamp(o.reputation + reputation_delta);
         o.consistency = clamp(o.consistency + consistency_delta);
         tbl.official_id().update(o);
     }
+    Ok(())
 }
 
 #[reducer]
@@ -938,12 +3578,14 @@ pub fn var_review_record(
     decision: String,
     reason: String,
     meta_json: String,
-) {
+) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend", "moderator"])?;
     let payload = format!(
         "{{\"match_id\":\"{}\",\"ts_ms\":{},\"decision\":\"{}\",\"reason\":\"{}\",\"meta\":{}}}",
         match_id, ts_ms, decision, reason, meta_json
     );
     append_event(ctx, "var_review", 0, payload, Some(match_id));
+    Ok(())
 }
 
 #[reducer]
@@ -955,7 +3597,8 @@ pub fn commentary_append(
     lang: String,
     text: String,
     meta_json: String,
-) {
+) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend"])?;
     let id = new_id(ctx, "cmt", &match_id);
     ctx.db().commentary_log().insert(CommentaryLog {
         id,
@@ -966,14 +3609,17 @@ pub fn commentary_append(
         text,
         meta_json,
     });
+    Ok(())
 }
 
 #[reducer]
-pub fn official_set_active(ctx: &ReducerContext, official_id: String, active: bool) {
+pub fn official_set_active(ctx: &ReducerContext, official_id: String, active: bool) -> Result<(), ReducerError> {
+    require_role(ctx, &["backend", "moderator"])?;
     if let Some(mut o) = ctx.db().officials().official_id().find(&official_id) {
         o.active = active;
         ctx.db().officials().official_id().update(o);
     }
+    Ok(())
 }
 
 // --- Atomic purchase reducers ---
@@ -985,21 +3631,24 @@ pub fn marketplace_purchase_apply(
     buyer_fid: i64,
     listing_id: String,
     endpoint: String,
-) {
+) -> Result<(), ReducerError> {
+    // Only the backend can attest that the on-chain payment happened
+    require_role(ctx, &["backend"])?;
     // Idempotency: if tx already used, no-op
     if ctx.db().transaction_used().tx_hash().find(&tx_hash).is_some() {
-        return;
+        return Ok(());
     }
 
     // Close listing and transfer
     let listings = ctx.db().listing();
-    let mut l = listings.id().find(&listing_id).ok_or("listing_not_found").unwrap();
-    if l.status != "active" { panic!("listing_closed"); }
+    let mut l = listings.id().find(&listing_id).ok_or(ReducerError::ListingNotFound)?;
+    if l.status != "active" { return Err(ReducerError::ListingClosed); }
+    check_purchase_window(ctx, &l, buyer_fid)?;
     l.status = "closed".into();
     l.closed_at_ms = Some(now_ms(ctx));
     listings.id().update(l.clone());
     let evt = append_event(ctx, "ListingSold", buyer_fid, serde_json::to_string(&l).unwrap_or("{}".into()), Some(listing_id.clone()));
-    transfer_item(ctx, &l.item_id, l.seller_fid, buyer_fid, &evt.id).unwrap();
+    transfer_item(ctx, &l.item_id, l.seller_fid, buyer_fid, &evt.id, &l.id)?;
     on_item_transferred(ctx, &l.item_id, buyer_fid);
     push_inbox(ctx, l.seller_fid, format!("listing-sold-{}", evt.id), "listing_sold", "Item Sold!", "Your item was purchased.");
     push_inbox(ctx, buyer_fid, format!("listing-bought-{}", evt.id), "listing_bought", "Purchase Complete", "You bought an item.");
@@ -1011,6 +3660,7 @@ pub fn marketplace_purchase_apply(
         used_by_fid: buyer_fid,
         endpoint,
     });
+    Ok(())
 }
 
 #[reducer]
@@ -1021,23 +3671,31 @@ pub fn auction_buy_now_apply(
     auction_id: String,
     buy_now_wei: String,
     endpoint: String,
-) {
+) -> Result<(), ReducerError> {
+    // Only the backend can attest that the on-chain payment happened
+    require_role(ctx, &["backend"])?;
     // Idempotency: if tx already used, no-op
     if ctx.db().transaction_used().tx_hash().find(&tx_hash).is_some() {
-        return;
+        return Ok(());
     }
 
     let auctions = ctx.db().auction();
-    let mut a = auctions.id().find(&auction_id).ok_or("auction_not_found").unwrap();
-    if a.status != "active" { panic!("auction_closed"); }
-    if a.buy_now_wei.as_deref() != Some(&buy_now_wei) { panic!("invalid_buy_now"); }
+    let mut a = auctions.id().find(&auction_id).ok_or(ReducerError::AuctionNotFound)?;
+    if a.status != "active" { return Err(ReducerError::AuctionClosed); }
+    if a.seller_fid == buyer_fid { return Err(ReducerError::SameFid); }
+    if now_ms(ctx) >= a.ends_at_ms { return Err(ReducerError::AuctionEnded); }
+    let buy_now_wei = wei::parse_positive(&buy_now_wei)?;
+    if a.buy_now_wei != Some(buy_now_wei) { return Err(ReducerError::InvalidBuyNow); }
+    // Paid on-chain; only the escrowed top bid needs unwinding
+    refund_top_bid(ctx, &a)?;
+    clear_proxy_bids(ctx, &auction_id);
     let evt = append_event(ctx, "AuctionBuyNow", buyer_fid, serde_json::to_string(&a).unwrap_or("{}".into()), Some(auction_id.clone()));
     a.status = "finalized".into();
     a.finalized_at_ms = Some(now_ms(ctx));
     a.top_bid_wei = Some(buy_now_wei);
     a.top_bidder_fid = Some(buyer_fid);
     auctions.id().update(a.clone());
-    transfer_item(ctx, &a.item_id, a.seller_fid, buyer_fid, &evt.id).unwrap();
+    transfer_item(ctx, &a.item_id, a.seller_fid, buyer_fid, &evt.id, &a.id)?;
     on_item_transferred(ctx, &a.item_id, buyer_fid);
 
     // Mark tx as used atomically
@@ -1047,4 +3705,22 @@ pub fn auction_buy_now_apply(
         used_by_fid: buyer_fid,
         endpoint,
     });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hold_blocks_forwarded_fid_without_exemption() {
+        // The backend acting for an ordinary fid gets no bypass from its own roles
+        assert_eq!(check_hold(2_000, 1_000, false), Err(ReducerError::InHold));
+    }
+
+    #[test]
+    fn hold_lifts_on_expiry_or_exemption() {
+        assert_eq!(check_hold(2_000, 2_000, false), Ok(()));
+        assert_eq!(check_hold(2_000, 1_000, true), Ok(()));
+    }
 }
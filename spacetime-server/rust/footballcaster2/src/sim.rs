@@ -0,0 +1,333 @@
+//! Deterministic match simulation.
+//!
+//! A port of the minute-by-minute model in `src/lib/match/engine.ts` (and the
+//! official heuristics in `src/lib/npc/officials.ts`). The simulator is pure:
+//! callers gather squads and officials from the database and pass a seed, and
+//! the same seed and inputs always produce the same scoreline and events.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use spacetimedb::rand::rngs::StdRng;
+use spacetimedb::rand::{Rng, SeedableRng};
+
+use crate::{Official, PlayerMatchEvents};
+
+pub struct SimPlayer {
+    pub player_id: String,
+    pub rating: i32,  // 0..100, already adjusted for age
+    pub fatigue: i32, // 0..100 (100 = very tired)
+    pub morale: i32,  // 0..100
+}
+
+pub struct SimTeam {
+    pub squad_rating: i32, // 0..100
+    pub players: Vec<SimPlayer>,
+}
+
+#[derive(Default)]
+pub struct SimOfficials {
+    pub referee: Option<Official>,
+    pub assistant_left: Option<Official>,
+    pub assistant_right: Option<Official>,
+    pub var: Option<Official>,
+}
+
+#[derive(Serialize, Clone)]
+pub struct SimEvent {
+    pub minute: u8,
+    pub kind: &'static str, // goal|shot|save|corner|foul|yellow_card|red_card|offside|injury|var_decision
+    pub team: &'static str, // home|away
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub player_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct SimResult {
+    pub home: i64,
+    pub away: i64,
+    pub source: &'static str,
+    pub seed: u64,
+    pub events: Vec<SimEvent>,
+    // Per-player tallies for PlayerState updates; not part of the stored result
+    #[serde(skip)]
+    pub stats: BTreeMap<String, PlayerMatchEvents>,
+    // Minute each sent-off player left the pitch
+    #[serde(skip)]
+    pub sent_off: BTreeMap<String, i32>,
+}
+
+/// Combines the match id with reducer entropy so two matches simulated in the
+/// same transaction still diverge. FNV-1a keeps the hash stable across builds.
+pub fn seed_for(match_id: &str, entropy: u64) -> u64 {
+    let mut h: u64 = 0xcbf2_9ce4_8422_2325;
+    for b in match_id.bytes() {
+        h ^= b as u64;
+        h = h.wrapping_mul(0x0100_0000_01b3);
+    }
+    h ^ entropy
+}
+
+fn foul_probability(referee: Option<&Official>) -> f64 {
+    referee.map_or(0.06, |r| 0.04 + r.strictness as f64 / 2000.0)
+}
+
+fn card_severity(referee: Option<&Official>) -> f64 {
+    referee.map_or(1.0, |r| 1.0 + (r.strictness + r.reputation) as f64 / 200.0)
+}
+
+fn offside_noise(assistant: Option<&Official>) -> f64 {
+    assistant.map_or(0.02, |a| {
+        let tol = a.offside_tolerance as f64 / 100.0;
+        let inconsistency = 1.0 - a.consistency as f64 / 100.0;
+        (0.02 + 0.08 * (inconsistency + (1.0 - tol)) / 2.0).max(0.0)
+    })
+}
+
+fn var_review_chance(var: Option<&Official>) -> f64 {
+    var.map_or(0.0, |v| 0.05 + v.var_propensity as f64 / 200.0)
+}
+
+/// Pre-match strength: player ratings scaled by freshness and morale, blended
+/// with the squad rating. A team without players plays on squad rating alone.
+fn base_strength(team: &SimTeam) -> f64 {
+    if team.players.is_empty() { return team.squad_rating.max(1) as f64; }
+    let sum: f64 = team.players.iter().map(|p| {
+        let freshness = 1.0 - p.fatigue.clamp(0, 100) as f64 / 200.0; // 0.5..1.0
+        let spirit = 0.9 + p.morale.clamp(0, 100) as f64 / 500.0;     // 0.9..1.1
+        p.rating.clamp(1, 100) as f64 * freshness * spirit
+    }).sum();
+    let avg = sum / team.players.len() as f64;
+    avg * 0.8 + team.squad_rating.clamp(0, 100) as f64 * 0.2
+}
+
+struct Side<'a> {
+    team: &'a SimTeam,
+    label: &'static str,
+    strength: f64,
+    stamina: f64,
+    on_pitch: Vec<usize>,
+    yellows: BTreeMap<usize, i32>,
+}
+
+impl Side<'_> {
+    fn current_strength(&self) -> f64 {
+        let numbers = if self.team.players.is_empty() { 1.0 } else { self.on_pitch.len() as f64 / self.team.players.len() as f64 };
+        self.strength * (self.stamina / 100.0) * (0.5 + numbers * 0.5)
+    }
+
+    fn pick(&self, rng: &mut StdRng) -> Option<usize> {
+        if self.on_pitch.is_empty() { return None; }
+        Some(self.on_pitch[rng.gen_range(0..self.on_pitch.len())])
+    }
+
+    fn player_id(&self, idx: Option<usize>) -> Option<String> {
+        idx.map(|i| self.team.players[i].player_id.clone())
+    }
+}
+
+pub fn simulate(seed: u64, home: &SimTeam, away: &SimTeam, officials: &SimOfficials) -> SimResult {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let new_side = |team, label| Side {
+        team,
+        label,
+        strength: base_strength(team),
+        stamina: 100.0,
+        on_pitch: (0..team.players.len()).collect(),
+        yellows: BTreeMap::new(),
+    };
+    let mut sides = [new_side(home, "home"), new_side(away, "away")];
+    let mut score = [0i64, 0i64];
+    let mut events: Vec<SimEvent> = Vec::new();
+    let mut stats: BTreeMap<String, PlayerMatchEvents> = BTreeMap::new();
+    let mut sent_off: BTreeMap<String, i32> = BTreeMap::new();
+
+    let referee = officials.referee.as_ref();
+    let foul_threshold = 0.25 + foul_probability(referee).clamp(0.0, 0.2);
+    let yellow_chance = (0.2 * card_severity(referee)).min(0.5);
+    let var_chance = var_review_chance(officials.var.as_ref());
+
+    for minute in 1..=90u8 {
+        for side in sides.iter_mut() {
+            side.stamina = (side.stamina - if minute > 60 { 0.75 } else { 0.5 }).max(0.0);
+        }
+        let (h, a) = (sides[0].current_strength(), sides[1].current_strength());
+        let home_chance = if h + a > 0.0 { h / (h + a) } else { 0.5 };
+        let roll: f64 = rng.gen();
+        let attacking = if rng.gen::<f64>() < home_chance { 0 } else { 1 };
+
+        if roll < 0.04 {
+            let side = &sides[attacking];
+            let scorer = side.pick(&mut rng);
+            let assister = side.pick(&mut rng).filter(|&i| Some(i) != scorer && rng.gen::<f64>() < 0.7);
+            let reviewed = var_chance > 0.0 && rng.gen::<f64>() < var_chance;
+            let overturned = reviewed && rng.gen::<f64>() < 0.25;
+            events.push(SimEvent { minute, kind: "goal", team: side.label, player_id: side.player_id(scorer), detail: None });
+            if reviewed && !overturned {
+                events.push(SimEvent { minute, kind: "var_decision", team: side.label, player_id: None, detail: Some("stands".into()) });
+            }
+            if overturned {
+                let reason = ["offside", "foul", "handball"][rng.gen_range(0..3)];
+                events.push(SimEvent { minute, kind: "var_decision", team: side.label, player_id: None, detail: Some(format!("disallowed:{}", reason)) });
+                continue;
+            }
+            score[attacking] += 1;
+            if let Some(id) = side.player_id(scorer) { stats.entry(id).or_default().goals += 1; }
+            if let Some(id) = side.player_id(assister) { stats.entry(id).or_default().assists += 1; }
+        } else if roll < 0.15 {
+            let side = &sides[attacking];
+            let assistant = if rng.gen::<f64>() < 0.5 { officials.assistant_left.as_ref() } else { officials.assistant_right.as_ref() };
+            if rng.gen::<f64>() < offside_noise(assistant) {
+                events.push(SimEvent { minute, kind: "offside", team: side.label, player_id: None, detail: None });
+                continue;
+            }
+            let shooter = side.player_id(side.pick(&mut rng));
+            let kind = if rng.gen::<f64>() < 0.4 { "save" } else { "shot" };
+            events.push(SimEvent { minute, kind, team: side.label, player_id: shooter, detail: None });
+        } else if roll < 0.25 {
+            events.push(SimEvent { minute, kind: "corner", team: sides[attacking].label, player_id: None, detail: None });
+        } else if roll < foul_threshold {
+            let fouling = if rng.gen::<f64>() < 0.5 { 0 } else { 1 };
+            let offender = sides[fouling].pick(&mut rng);
+            let offender_id = sides[fouling].player_id(offender);
+            if rng.gen::<f64>() < yellow_chance {
+                let side = &mut sides[fouling];
+                events.push(SimEvent { minute, kind: "yellow_card", team: side.label, player_id: offender_id.clone(), detail: None });
+                if let (Some(idx), Some(id)) = (offender, offender_id) {
+                    stats.entry(id.clone()).or_default().yellow_cards += 1;
+                    let count = side.yellows.entry(idx).or_insert(0);
+                    *count += 1;
+                    if *count >= 2 {
+                        events.push(SimEvent { minute, kind: "red_card", team: side.label, player_id: Some(id.clone()), detail: Some("second_yellow".into()) });
+                        stats.entry(id.clone()).or_default().red_card = true;
+                        sent_off.insert(id, minute as i32);
+                        side.on_pitch.retain(|&i| i != idx);
+                    }
+                }
+            } else {
+                events.push(SimEvent { minute, kind: "foul", team: sides[fouling].label, player_id: offender_id, detail: None });
+            }
+            // Rough challenges occasionally injure the fouled player
+            let victim_side = &sides[1 - fouling];
+            if rng.gen::<f64>() < 0.03 {
+                if let Some(id) = victim_side.player_id(victim_side.pick(&mut rng)) {
+                    let sev = rng.gen::<f64>();
+                    let severity = if sev < 0.7 { "minor" } else if sev < 0.95 { "moderate" } else { "severe" };
+                    events.push(SimEvent { minute, kind: "injury", team: victim_side.label, player_id: Some(id.clone()), detail: Some(severity.into()) });
+                    stats.entry(id).or_default().injury = Some(severity.into());
+                }
+            }
+        }
+    }
+
+    SimResult { home: score[0], away: score[1], source: "sim", seed, events, stats, sent_off }
+}
+
+/// How a knockout tie level after 90 minutes was settled.
+#[derive(Serialize)]
+pub struct Decider {
+    pub extra_time: (i64, i64),
+    // Shoot-out score when extra time is also level
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub penalties: Option<(i64, i64)>,
+}
+
+/// Plays 30 minutes of extra time and, if still level, a shoot-out: five
+/// kicks each, then sudden death. The stronger side scores and converts more.
+pub fn extra_time_and_penalties(seed: u64, home: &SimTeam, away: &SimTeam) -> Decider {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let (h, a) = (base_strength(home), base_strength(away));
+    let home_share = if h + a > 0.0 { h / (h + a) } else { 0.5 };
+    let mut extra = [0i64, 0i64];
+    for _ in 91..=120 {
+        // Tired legs: a little below the 90-minute goal rate
+        if rng.gen::<f64>() < 0.03 {
+            extra[if rng.gen::<f64>() < home_share { 0 } else { 1 }] += 1;
+        }
+    }
+    if extra[0] != extra[1] { return Decider { extra_time: (extra[0], extra[1]), penalties: None }; }
+
+    let rates = [0.65 + 0.2 * home_share, 0.65 + 0.2 * (1.0 - home_share)]; // 0.65..0.85
+    let mut pens = [0i64, 0i64];
+    let mut kicks = 0;
+    loop {
+        kicks += 1;
+        for (score, rate) in pens.iter_mut().zip(rates) {
+            if rng.gen::<f64>() < rate { *score += 1; }
+        }
+        if kicks >= 5 && pens[0] != pens[1] { break; }
+    }
+    Decider { extra_time: (extra[0], extra[1]), penalties: Some((pens[0], pens[1])) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn team(prefix: &str, rating: i32) -> SimTeam {
+        SimTeam {
+            squad_rating: rating,
+            players: (0..11).map(|i| SimPlayer { player_id: format!("{}-{}", prefix, i), rating, fatigue: 20, morale: 60 }).collect(),
+        }
+    }
+
+    fn fingerprint(res: &SimResult) -> String {
+        format!("{}|{:?}", serde_json::to_string(res).unwrap(), res.sent_off)
+    }
+
+    #[test]
+    fn seed_for_is_stable_and_depends_on_the_match() {
+        assert_eq!(seed_for("pvp-1", 42), seed_for("pvp-1", 42));
+        assert_ne!(seed_for("pvp-1", 42), seed_for("pvp-2", 42));
+        assert_ne!(seed_for("pvp-1", 42), seed_for("pvp-1", 43));
+    }
+
+    #[test]
+    fn simulate_is_deterministic_for_a_seed() {
+        let (home, away) = (team("h", 70), team("a", 65));
+        for entropy in 0..20 {
+            let seed = seed_for("pvp-determinism", entropy);
+            let a = simulate(seed, &home, &away, &SimOfficials::default());
+            let b = simulate(seed, &home, &away, &SimOfficials::default());
+            assert_eq!(fingerprint(&a), fingerprint(&b));
+            assert_eq!(a.seed, seed);
+        }
+    }
+
+    #[test]
+    fn simulate_varies_with_the_seed() {
+        let (home, away) = (team("h", 70), team("a", 65));
+        let runs: Vec<String> = (0..20).map(|e| fingerprint(&simulate(seed_for("pvp-variety", e), &home, &away, &SimOfficials::default()))).collect();
+        assert!(runs.iter().any(|r| r != &runs[0]));
+    }
+
+    #[test]
+    fn extra_time_and_penalties_is_deterministic() {
+        let (home, away) = (team("h", 60), team("a", 60));
+        for entropy in 0..50 {
+            let seed = seed_for("tie-determinism", entropy);
+            let a = extra_time_and_penalties(seed, &home, &away);
+            let b = extra_time_and_penalties(seed, &home, &away);
+            assert_eq!(serde_json::to_string(&a).unwrap(), serde_json::to_string(&b).unwrap());
+        }
+    }
+
+    #[test]
+    fn ties_are_always_decided() {
+        for (home, away) in [(team("h", 60), team("a", 60)), (team("h", 90), team("a", 30)), (SimTeam { squad_rating: 0, players: vec![] }, team("a", 50))] {
+            for entropy in 0..500 {
+                let d = extra_time_and_penalties(seed_for("tie-decided", entropy), &home, &away);
+                match d.penalties {
+                    // A shoot-out only follows a level extra time, and never ends level
+                    Some((h, a)) => {
+                        assert_eq!(d.extra_time.0, d.extra_time.1);
+                        assert_ne!(h, a);
+                    }
+                    None => assert_ne!(d.extra_time.0, d.extra_time.1),
+                }
+            }
+        }
+    }
+}
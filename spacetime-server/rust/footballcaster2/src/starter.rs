@@ -0,0 +1,94 @@
+//! Starter pack generation.
+//!
+//! The server-side replacement for `src/lib/starter/generate.ts`: 18 players
+//! with overall ratings uniform in 60..=89, now with positions, names from a
+//! fixed pool and a full attribute card shaped by position. Like the match
+//! simulator it is pure and seeded, so a pack can be regenerated from the seed
+//! recorded in its `starter_pack_granted` event. Player ids are assigned by
+//! the caller, which owns the uniqueness check.
+
+use spacetimedb::rand::rngs::StdRng;
+use spacetimedb::rand::{Rng, SeedableRng};
+
+pub const PACK_SIZE: usize = 18;
+
+// Two full outfield lines plus a keeper on the bench: 2 GK, 6 DEF, 6 MID, 4 FWD
+const POSITIONS: [(&str, usize); 4] = [("GK", 2), ("DEF", 6), ("MID", 6), ("FWD", 4)];
+
+const FIRST_NAMES: [&str; 24] = [
+    "Alex", "Bruno", "Carlos", "Dani", "Emil", "Felix", "Gabriel", "Hugo",
+    "Ivan", "Jonas", "Kai", "Luca", "Marco", "Nico", "Oscar", "Pablo",
+    "Rafael", "Sami", "Theo", "Umar", "Viktor", "Wes", "Yusuf", "Zane",
+];
+
+const LAST_NAMES: [&str; 24] = [
+    "Almeida", "Bakker", "Costa", "Diallo", "Eriksen", "Fischer", "Garcia", "Haddad",
+    "Ito", "Jansen", "Kovac", "Lindqvist", "Moreau", "Nakamura", "Okafor", "Petrov",
+    "Quinn", "Rossi", "Silva", "Tanaka", "Uribe", "Varga", "Walsh", "Yilmaz",
+];
+
+// Attribute offsets from the overall rating per primary position:
+// pace, shooting, passing, defending, physical, goalkeeping
+const PROFILES: [(&str, [i32; 6]); 4] = [
+    ("GK", [-25, -40, -15, -20, -5, 0]),
+    ("DEF", [-5, -25, -8, 2, 3, -50]),
+    ("MID", [-3, -6, 2, -8, -4, -50]),
+    ("FWD", [3, 2, -6, -35, -2, -50]),
+];
+
+// Positions an outfielder may also cover
+const SECONDARY: [(&str, &[&str]); 3] = [("DEF", &["MID"]), ("MID", &["DEF", "FWD"]), ("FWD", &["MID"])];
+
+pub struct Attributes {
+    pub pace: i32,
+    pub shooting: i32,
+    pub passing: i32,
+    pub defending: i32,
+    pub physical: i32,
+    pub goalkeeping: i32,
+}
+
+pub struct GeneratedPlayer {
+    pub name: String,
+    pub positions: Vec<&'static str>, // primary first: GK|DEF|MID|FWD
+    pub rating: i32,                  // overall, 60..=89
+    pub attributes: Attributes,
+    pub preferred_foot: &'static str, // left|right|both
+    pub potential: i32,               // rating..=99
+}
+
+fn roll_attributes(rng: &mut StdRng, position: &str, rating: i32) -> Attributes {
+    let offsets = PROFILES.iter().find(|(p, _)| *p == position).map_or([0; 6], |(_, o)| *o);
+    let mut roll = |i: usize| (rating + offsets[i] + rng.gen_range(-5..=5)).clamp(1, 99);
+    Attributes { pace: roll(0), shooting: roll(1), passing: roll(2), defending: roll(3), physical: roll(4), goalkeeping: roll(5) }
+}
+
+fn roll_positions(rng: &mut StdRng, primary: &'static str) -> Vec<&'static str> {
+    let mut positions = vec![primary];
+    if let Some((_, others)) = SECONDARY.iter().find(|(p, _)| *p == primary) {
+        if rng.gen::<f64>() < 0.3 { positions.push(others[rng.gen_range(0..others.len())]); }
+    }
+    positions
+}
+
+pub fn generate(seed: u64) -> Vec<GeneratedPlayer> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut players = Vec::with_capacity(PACK_SIZE);
+    for (position, count) in POSITIONS {
+        for _ in 0..count {
+            let first = FIRST_NAMES[rng.gen_range(0..FIRST_NAMES.len())];
+            let last = LAST_NAMES[rng.gen_range(0..LAST_NAMES.len())];
+            let rating = rng.gen_range(60..90);
+            let foot = rng.gen::<f64>();
+            players.push(GeneratedPlayer {
+                name: format!("{} {}", first, last),
+                positions: roll_positions(&mut rng, position),
+                rating,
+                attributes: roll_attributes(&mut rng, position, rating),
+                preferred_foot: if foot < 0.7 { "right" } else if foot < 0.95 { "left" } else { "both" },
+                potential: (rating + rng.gen_range(0..=15)).min(99),
+            });
+        }
+    }
+    players
+}
@@ -0,0 +1,138 @@
+//! FBC amounts in wei as 256-bit unsigned integers.
+//!
+//! Amounts cross the reducer boundary as decimal strings (what the Next.js client
+//! already sends) and are parsed strictly here; arithmetic is always checked.
+
+pub use spacetimedb::sats::u256;
+
+use crate::error::ReducerError;
+
+/// Largest number of decimal digits a u256 can have (2^256 - 1 has 78).
+const MAX_DIGITS: usize = 78;
+
+/// Parses a plain base-10 wei amount. Signs, whitespace, hex and exponents are
+/// rejected rather than silently coerced to zero.
+pub fn parse(s: &str) -> Result<u256, ReducerError> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) { return Err(ReducerError::InvalidAmount); }
+    let digits = s.trim_start_matches('0');
+    if digits.len() > MAX_DIGITS { return Err(ReducerError::AmountOverflow); }
+    u256::from_str_radix(s, 10).map_err(|_| ReducerError::AmountOverflow)
+}
+
+/// Like [`parse`], but zero is not an acceptable price or bid.
+pub fn parse_positive(s: &str) -> Result<u256, ReducerError> {
+    let v = parse(s)?;
+    if v == u256::ZERO { return Err(ReducerError::InvalidAmount); }
+    Ok(v)
+}
+
+pub fn parse_opt(s: Option<&str>) -> Result<Option<u256>, ReducerError> {
+    s.map(parse_positive).transpose()
+}
+
+pub fn add(a: u256, b: u256) -> Result<u256, ReducerError> {
+    a.checked_add(b).ok_or(ReducerError::AmountOverflow)
+}
+
+/// Subtracts `b` from `a`, reporting `err` when `a` is too small.
+pub fn sub(a: u256, b: u256, err: ReducerError) -> Result<u256, ReducerError> {
+    a.checked_sub(b).ok_or(err)
+}
+
+/// Smallest acceptable next bid: `current` plus 2%, rounded up.
+pub fn min_next_bid(current: u256) -> Result<u256, ReducerError> {
+    let fifty = u256::new(50);
+    let step = current / fifty;
+    let step = if current % fifty == u256::ZERO { step } else { step + u256::ONE };
+    add(current, step)
+}
+
+/// Serde adapters so event payloads keep encoding amounts as decimal strings.
+pub mod decimal {
+    use super::u256;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(v: &u256, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_str(v)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<u256, D::Error> {
+        let s = String::deserialize(d)?;
+        super::parse(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+pub mod decimal_opt {
+    use super::u256;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(v: &Option<u256>, s: S) -> Result<S::Ok, S::Error> {
+        match v {
+            Some(v) => s.collect_str(v),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<u256>, D::Error> {
+        let s = Option::<String>::deserialize(d)?;
+        s.map(|s| super::parse(&s).map_err(serde::de::Error::custom)).transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rejects_malformed_input() {
+        for s in ["", "+1", "-1", " 1", "1 ", "0x10", "1e18", "1.5", "abc"] {
+            assert_eq!(parse(s), Err(ReducerError::InvalidAmount), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn parse_accepts_leading_zeros_and_max() {
+        assert_eq!(parse("0"), Ok(u256::ZERO));
+        assert_eq!(parse("000123"), Ok(u256::new(123)));
+        let max = u256::MAX.to_string();
+        assert_eq!(parse(&max), Ok(u256::MAX));
+        assert_eq!(parse(&format!("000{}", max)), Ok(u256::MAX));
+    }
+
+    #[test]
+    fn parse_rejects_overflow() {
+        // 2^256, one past the largest u256
+        let too_big = "115792089237316195423570985008687907853269984665640564039457584007913129639936";
+        assert_eq!(parse(too_big), Err(ReducerError::AmountOverflow));
+        assert_eq!(parse(&"9".repeat(MAX_DIGITS + 1)), Err(ReducerError::AmountOverflow));
+    }
+
+    #[test]
+    fn parse_positive_rejects_zero() {
+        assert_eq!(parse_positive("0"), Err(ReducerError::InvalidAmount));
+        assert_eq!(parse_positive("000"), Err(ReducerError::InvalidAmount));
+        assert_eq!(parse_positive("1"), Ok(u256::ONE));
+        assert_eq!(parse_opt(Some("0")), Err(ReducerError::InvalidAmount));
+        assert_eq!(parse_opt(None), Ok(None));
+    }
+
+    #[test]
+    fn add_and_sub_are_checked() {
+        assert_eq!(add(u256::new(2), u256::new(3)), Ok(u256::new(5)));
+        assert_eq!(add(u256::MAX, u256::ONE), Err(ReducerError::AmountOverflow));
+        assert_eq!(sub(u256::new(5), u256::new(3), ReducerError::EscrowUnderflow), Ok(u256::new(2)));
+        assert_eq!(sub(u256::new(3), u256::new(5), ReducerError::InsufficientFunds), Err(ReducerError::InsufficientFunds));
+    }
+
+    #[test]
+    fn min_next_bid_rounds_the_step_up() {
+        // Any non-zero bid must be beaten by at least one wei
+        assert_eq!(min_next_bid(u256::ONE), Ok(u256::new(2)));
+        assert_eq!(min_next_bid(u256::new(49)), Ok(u256::new(50)));
+        assert_eq!(min_next_bid(u256::new(50)), Ok(u256::new(51)));
+        assert_eq!(min_next_bid(u256::new(51)), Ok(u256::new(53)));
+        assert_eq!(min_next_bid(u256::new(100)), Ok(u256::new(102)));
+        assert_eq!(min_next_bid(u256::ZERO), Ok(u256::ZERO));
+        assert_eq!(min_next_bid(u256::MAX), Err(ReducerError::AmountOverflow));
+    }
+}
@@ -0,0 +1,43 @@
+/**
+ * POST /api/admin/cup - Create a cup
+ * PATCH /api/admin/cup - Start (make the draw) or cancel a cup in registration
+ */
+
+import type { NextRequest } from 'next/server';
+import { requireAuth, isAdminFID, type AuthContext } from '@/lib/middleware/auth';
+import { stCupCancel, stCupCreate, stCupStart } from '@/lib/spacetime/api';
+import { cupAdminSchema, cupCreateSchema } from '@/lib/middleware/validation';
+import { withErrorHandling, validateBody, ok, forbidden } from '@/lib/api/http';
+
+export const runtime = 'nodejs';
+export const dynamic = 'force-dynamic';
+
+async function createHandler(req: NextRequest, ctx: AuthContext): Promise<Response> {
+  return withErrorHandling(async () => {
+    if (!isAdminFID(ctx.fid)) return forbidden('forbidden');
+
+    const parsed = await validateBody(req, cupCreateSchema);
+    if (!parsed.ok) return parsed.res;
+
+    const { name, entryFeeFbcWei, maxEntrants, groupStage, prizeSplitBps } = parsed.data;
+    await stCupCreate(name, entryFeeFbcWei, maxEntrants, groupStage, prizeSplitBps);
+    return ok({ success: true });
+  });
+}
+
+async function actionHandler(req: NextRequest, ctx: AuthContext): Promise<Response> {
+  return withErrorHandling(async () => {
+    if (!isAdminFID(ctx.fid)) return forbidden('forbidden');
+
+    const parsed = await validateBody(req, cupAdminSchema);
+    if (!parsed.ok) return parsed.res;
+
+    const { cupId, action } = parsed.data;
+    if (action === 'start') await stCupStart(cupId);
+    else await stCupCancel(cupId);
+    return ok({ success: true });
+  });
+}
+
+export const POST = requireAuth(createHandler);
+export const PATCH = requireAuth(actionHandler);
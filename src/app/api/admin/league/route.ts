@@ -0,0 +1,28 @@
+/**
+ * POST /api/admin/league
+ * Create a league division with its starting managers (human or NPC fids)
+ */
+
+import type { NextRequest } from 'next/server';
+import { requireAuth, isAdminFID, type AuthContext } from '@/lib/middleware/auth';
+import { stLeagueCreate } from '@/lib/spacetime/api';
+import { leagueCreateSchema } from '@/lib/middleware/validation';
+import { withErrorHandling, validateBody, ok, forbidden } from '@/lib/api/http';
+
+export const runtime = 'nodejs';
+export const dynamic = 'force-dynamic';
+
+async function handler(req: NextRequest, ctx: AuthContext): Promise<Response> {
+  return withErrorHandling(async () => {
+    if (!isAdminFID(ctx.fid)) return forbidden('forbidden');
+
+    const parsed = await validateBody(req, leagueCreateSchema);
+    if (!parsed.ok) return parsed.res;
+
+    const { pyramid, name, division, promotionSlots, relegationSlots, memberFids } = parsed.data;
+    await stLeagueCreate(pyramid, name, division, promotionSlots, relegationSlots, memberFids);
+    return ok({ success: true });
+  });
+}
+
+export const POST = requireAuth(handler);
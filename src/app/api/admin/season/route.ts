@@ -0,0 +1,28 @@
+/**
+ * POST /api/admin/season
+ * Start the next season of a league pyramid (fixtures, standings, kickoff timers)
+ */
+
+import type { NextRequest } from 'next/server';
+import { requireAuth, isAdminFID, type AuthContext } from '@/lib/middleware/auth';
+import { stSeasonStart } from '@/lib/spacetime/api';
+import { seasonStartSchema } from '@/lib/middleware/validation';
+import { withErrorHandling, validateBody, ok, forbidden } from '@/lib/api/http';
+
+export const runtime = 'nodejs';
+export const dynamic = 'force-dynamic';
+
+async function handler(req: NextRequest, ctx: AuthContext): Promise<Response> {
+  return withErrorHandling(async () => {
+    if (!isAdminFID(ctx.fid)) return forbidden('forbidden');
+
+    const parsed = await validateBody(req, seasonStartSchema);
+    if (!parsed.ok) return parsed.res;
+
+    const { pyramid, mode, roundIntervalHours, firstKickoffMs } = parsed.data;
+    await stSeasonStart(pyramid, mode, roundIntervalHours, firstKickoffMs ?? Date.now());
+    return ok({ success: true });
+  });
+}
+
+export const POST = requireAuth(handler);
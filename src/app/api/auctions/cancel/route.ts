@@ -0,0 +1,25 @@
+/**
+ * POST /api/auctions/cancel
+ * Cancel an auction (free before the first bid, penalised after)
+ */
+
+import { type NextRequest } from 'next/server';
+import { stCancelAuction } from '@/lib/spacetime/api';
+import { cancelAuctionSchema } from '@/lib/middleware/validation';
+import { requireAuth } from '@/lib/middleware/auth';
+import { withErrorHandling, validateBody, ok } from '@/lib/api/http';
+
+export const runtime = 'nodejs';
+
+async function handler(req: NextRequest, ctx: { fid: number }): Promise<Response> {
+  return withErrorHandling(async () => {
+    const parsed = await validateBody(req, cancelAuctionSchema);
+    if (!parsed.ok) return parsed.res;
+
+    // Reducer enforces seller ownership and charges any penalty
+    await stCancelAuction(ctx.fid, parsed.data.auctionId);
+    return ok({ success: true });
+  });
+}
+
+export const POST = requireAuth(handler);
@@ -0,0 +1,38 @@
+/**
+ * POST /api/auctions/max-bid
+ * Register a maximum (proxy) bid; the module bids on the caller's behalf up to it
+ */
+
+import { type NextRequest } from 'next/server';
+import { stGetAuction, stSetMaxBid } from '@/lib/spacetime/api';
+import { setMaxBidSchema } from '@/lib/middleware/validation';
+import { requireAuth } from '@/lib/middleware/auth';
+import { withErrorHandling, validateBody, badRequest, notFound, ok } from '@/lib/api/http';
+
+export const runtime = 'nodejs';
+
+async function handler(req: NextRequest, ctx: { fid: number }): Promise<Response> {
+  return withErrorHandling(async () => {
+    const parsed = await validateBody(req, setMaxBidSchema);
+    if (!parsed.ok) return parsed.res;
+
+    const { auctionId, maxFbcWei } = parsed.data;
+    const { fid } = ctx;
+
+    const auction = await stGetAuction(auctionId);
+    if (!auction || auction.status !== 'active') {
+      return notFound('Auction not found or closed');
+    }
+
+    if (auction.sellerFid === fid) {
+      return badRequest('Cannot bid on own auction');
+    }
+
+    await stSetMaxBid(auctionId, fid, maxFbcWei);
+    // The cap itself is never echoed back; the auction shows the effective top bid
+    const updated = await stGetAuction(auctionId);
+    return ok({ success: true, auction: updated });
+  });
+}
+
+export const POST = requireAuth(handler);
@@ -25,7 +25,7 @@ async function postHandler(req: NextRequest, ctx: { fid: number }): Promise<Resp
     const parsed = await validateBody(req, createAuctionSchema);
     if (!parsed.ok) return parsed.res;
 
-    const { itemId, reserveFbcWei, durationH, buyNowFbcWei } = parsed.data;
+    const { itemId, reserveFbcWei, durationH, buyNowFbcWei, antiSnipe } = parsed.data;
     const { fid } = ctx;
 
     const duration = durationH ?? 48;
@@ -34,7 +34,8 @@ async function postHandler(req: NextRequest, ctx: { fid: number }): Promise<Resp
       itemId,
       reserveFbcWei,
       duration * 60 * 60,
-      buyNowFbcWei ?? null
+      buyNowFbcWei ?? null,
+      antiSnipe ?? null
     );
 
     return ok({ success: true, auction });
@@ -1,5 +1,5 @@
 import { ok, jsonError } from '@/lib/api/http';
-import { stPlayerStateRecoverTick, stPlayerAgeTick } from '@/lib/spacetime/api';
+import { stPlayerTicksSchedule } from '@/lib/spacetime/api';
 
 export const runtime = 'nodejs';
 export const dynamic = 'force-dynamic';
@@ -7,10 +7,8 @@ export const dynamic = 'force-dynamic';
 export async function GET(): Promise<Response> {
   const now = Date.now();
   try {
-    await stPlayerStateRecoverTick(now);
-    if (process.env.PLAYER_AGE_TICK === 'true') {
-      await stPlayerAgeTick();
-    }
+    // Recovery/aging ticks are scheduled inside the module; make sure they are armed.
+    await stPlayerTicksSchedule();
     return ok({ now });
   } catch (e) {
     // Standardized error response
@@ -22,7 +22,7 @@ export async function GET(): Promise<Response> {
     try {
       await executeNpcTick({ npc: n });
       const next = now + 60_000; // +1m default cooldown
-      await stNpcUpdateState(Number(n.npcFid), next, n.budgetFbcWei);
+      await stNpcUpdateState(Number(n.npcFid), next, String(n.budgetFbcWei));
     } catch (e) {
       console.warn('npc tick error', e);
     }
@@ -0,0 +1,20 @@
+/**
+ * GET /api/cups/[id]
+ * Cup entrants, group fixtures and knockout bracket
+ */
+
+import { type NextRequest } from 'next/server';
+import { stGetCup } from '@/lib/spacetime/api';
+import { ok, cache, notFound, withErrorHandling } from '@/lib/api/http';
+
+export const runtime = 'nodejs';
+export const dynamic = 'force-dynamic';
+
+export async function GET(_req: NextRequest, ctx: { params: Promise<{ id: string }> }): Promise<Response> {
+  return withErrorHandling(async () => {
+    const { id } = await ctx.params;
+    const cup = await stGetCup(id);
+    if (!cup) return notFound('Cup not found');
+    return ok({ cup }, { headers: cache.privateNoStore });
+  });
+}
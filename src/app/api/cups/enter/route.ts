@@ -0,0 +1,24 @@
+/**
+ * POST /api/cups/enter
+ * Enter a cup during registration, paying the entry fee from the FBC balance
+ */
+
+import { type NextRequest } from 'next/server';
+import { stCupEnter } from '@/lib/spacetime/api';
+import { cupEnterSchema } from '@/lib/middleware/validation';
+import { requireAuth } from '@/lib/middleware/auth';
+import { ok, withErrorHandling, validateBody } from '@/lib/api/http';
+
+export const runtime = 'nodejs';
+
+async function handler(req: NextRequest, ctx: { fid: number }): Promise<Response> {
+  return withErrorHandling(async () => {
+    const parsed = await validateBody(req, cupEnterSchema);
+    if (!parsed.ok) return parsed.res;
+
+    await stCupEnter(ctx.fid, parsed.data.cupId);
+    return ok({ success: true });
+  });
+}
+
+export const POST = requireAuth(handler);
@@ -0,0 +1,17 @@
+/**
+ * GET /api/cups - All cups with entry fee, prize pool and entrant counts
+ */
+
+import { stListCups } from '@/lib/spacetime/api';
+import { ok, cache, withErrorHandling } from '@/lib/api/http';
+
+export const runtime = 'nodejs';
+export const dynamic = 'force-dynamic';
+export const revalidate = 0;
+
+export async function GET(): Promise<Response> {
+  return withErrorHandling(async () => {
+    const cups = await stListCups();
+    return ok({ cups }, { headers: cache.privateNoStore });
+  });
+}
@@ -0,0 +1,20 @@
+/**
+ * GET /api/leagues/[id]
+ * League table (points, goal difference, form) and fixtures for the current season
+ */
+
+import { type NextRequest } from 'next/server';
+import { stGetLeague } from '@/lib/spacetime/api';
+import { ok, cache, notFound, withErrorHandling } from '@/lib/api/http';
+
+export const runtime = 'nodejs';
+export const dynamic = 'force-dynamic';
+
+export async function GET(_req: NextRequest, ctx: { params: Promise<{ id: string }> }): Promise<Response> {
+  return withErrorHandling(async () => {
+    const { id } = await ctx.params;
+    const league = await stGetLeague(id);
+    if (!league) return notFound('League not found');
+    return ok({ league }, { headers: cache.privateNoStore });
+  });
+}
@@ -0,0 +1,17 @@
+/**
+ * GET /api/leagues - All league divisions with member counts
+ */
+
+import { stListLeagues } from '@/lib/spacetime/api';
+import { ok, cache, withErrorHandling } from '@/lib/api/http';
+
+export const runtime = 'nodejs';
+export const dynamic = 'force-dynamic';
+export const revalidate = 0;
+
+export async function GET(): Promise<Response> {
+  return withErrorHandling(async () => {
+    const leagues = await stListLeagues();
+    return ok({ leagues }, { headers: cache.privateNoStore });
+  });
+}
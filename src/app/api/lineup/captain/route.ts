@@ -0,0 +1,25 @@
+/**
+ * POST /api/lineup/captain
+ * Give the armband to a starter (null removes the captain)
+ */
+
+import { type NextRequest } from 'next/server';
+import { stGetLineup, stLineupSetCaptain } from '@/lib/spacetime/api';
+import { lineupCaptainSchema } from '@/lib/middleware/validation';
+import { requireAuth } from '@/lib/middleware/auth';
+import { ok, withErrorHandling, validateBody } from '@/lib/api/http';
+
+export const runtime = 'nodejs';
+
+async function handler(req: NextRequest, ctx: { fid: number }): Promise<Response> {
+  return withErrorHandling(async () => {
+    const parsed = await validateBody(req, lineupCaptainSchema);
+    if (!parsed.ok) return parsed.res;
+
+    await stLineupSetCaptain(ctx.fid, parsed.data.itemId);
+    const lineup = await stGetLineup(ctx.fid);
+    return ok({ success: true, lineup });
+  });
+}
+
+export const POST = requireAuth(handler);
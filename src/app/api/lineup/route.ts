@@ -0,0 +1,34 @@
+/**
+ * GET /api/lineup - Current team sheet (formation, starters, subs, captain)
+ * POST /api/lineup - Set formation
+ */
+
+import { type NextRequest } from 'next/server';
+import { stGetLineup, stLineupSetFormation } from '@/lib/spacetime/api';
+import { lineupFormationSchema } from '@/lib/middleware/validation';
+import { requireAuth } from '@/lib/middleware/auth';
+import { ok, cache, withErrorHandling, validateBody } from '@/lib/api/http';
+
+export const runtime = 'nodejs';
+export const dynamic = 'force-dynamic';
+
+async function getHandler(_req: NextRequest, ctx: { fid: number }): Promise<Response> {
+  return withErrorHandling(async () => {
+    const lineup = await stGetLineup(ctx.fid);
+    return ok({ lineup }, { headers: cache.privateNoStore });
+  });
+}
+
+async function postHandler(req: NextRequest, ctx: { fid: number }): Promise<Response> {
+  return withErrorHandling(async () => {
+    const parsed = await validateBody(req, lineupFormationSchema);
+    if (!parsed.ok) return parsed.res;
+
+    await stLineupSetFormation(ctx.fid, parsed.data.formation);
+    const lineup = await stGetLineup(ctx.fid);
+    return ok({ success: true, lineup });
+  });
+}
+
+export const GET = requireAuth(getHandler);
+export const POST = requireAuth(postHandler);
@@ -0,0 +1,26 @@
+/**
+ * POST /api/lineup/slot
+ * Put an owned player in a lineup slot (0-10 starters, 11-17 subs), or clear it
+ */
+
+import { type NextRequest } from 'next/server';
+import { stGetLineup, stLineupAssign } from '@/lib/spacetime/api';
+import { lineupAssignSchema } from '@/lib/middleware/validation';
+import { requireAuth } from '@/lib/middleware/auth';
+import { ok, withErrorHandling, validateBody } from '@/lib/api/http';
+
+export const runtime = 'nodejs';
+
+async function handler(req: NextRequest, ctx: { fid: number }): Promise<Response> {
+  return withErrorHandling(async () => {
+    const parsed = await validateBody(req, lineupAssignSchema);
+    if (!parsed.ok) return parsed.res;
+
+    const { slot, itemId } = parsed.data;
+    await stLineupAssign(ctx.fid, slot, itemId);
+    const lineup = await stGetLineup(ctx.fid);
+    return ok({ success: true, lineup });
+  });
+}
+
+export const POST = requireAuth(handler);
@@ -0,0 +1,25 @@
+/**
+ * POST /api/market/cancel
+ * Withdraw an active listing
+ */
+
+import { type NextRequest } from 'next/server';
+import { stCancelListing } from '@/lib/spacetime/api';
+import { cancelListingSchema } from '@/lib/middleware/validation';
+import { requireAuth } from '@/lib/middleware/auth';
+import { withErrorHandling, validateBody, ok } from '@/lib/api/http';
+
+export const runtime = 'nodejs';
+
+async function handler(req: NextRequest, ctx: { fid: number }): Promise<Response> {
+  return withErrorHandling(async () => {
+    const parsed = await validateBody(req, cancelListingSchema);
+    if (!parsed.ok) return parsed.res;
+
+    // Reducer enforces seller ownership and the purchase window
+    await stCancelListing(ctx.fid, parsed.data.listingId);
+    return ok({ success: true });
+  });
+}
+
+export const POST = requireAuth(handler);
@@ -0,0 +1,26 @@
+/**
+ * POST /api/market/reprice
+ * Change the price of an active listing
+ */
+
+import { type NextRequest } from 'next/server';
+import { stUpdateListingPrice } from '@/lib/spacetime/api';
+import { updateListingPriceSchema } from '@/lib/middleware/validation';
+import { requireAuth } from '@/lib/middleware/auth';
+import { withErrorHandling, validateBody, ok } from '@/lib/api/http';
+
+export const runtime = 'nodejs';
+
+async function handler(req: NextRequest, ctx: { fid: number }): Promise<Response> {
+  return withErrorHandling(async () => {
+    const parsed = await validateBody(req, updateListingPriceSchema);
+    if (!parsed.ok) return parsed.res;
+
+    const { listingId, priceFbcWei } = parsed.data;
+    // Reducer enforces seller ownership and the purchase window
+    await stUpdateListingPrice(ctx.fid, listingId, priceFbcWei);
+    return ok({ success: true });
+  });
+}
+
+export const POST = requireAuth(handler);
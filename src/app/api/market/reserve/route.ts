@@ -0,0 +1,24 @@
+/**
+ * POST /api/market/reserve
+ * Freeze a listing's price before sending the on-chain payment
+ */
+
+import { type NextRequest } from 'next/server';
+import { stListingReservePurchase } from '@/lib/spacetime/api';
+import { reservePurchaseSchema } from '@/lib/middleware/validation';
+import { requireAuth } from '@/lib/middleware/auth';
+import { withErrorHandling, validateBody, ok } from '@/lib/api/http';
+
+export const runtime = 'nodejs';
+
+async function handler(req: NextRequest, ctx: { fid: number }): Promise<Response> {
+  return withErrorHandling(async () => {
+    const parsed = await validateBody(req, reservePurchaseSchema);
+    if (!parsed.ok) return parsed.res;
+
+    await stListingReservePurchase(parsed.data.listingId, ctx.fid);
+    return ok({ success: true });
+  });
+}
+
+export const POST = requireAuth(handler);
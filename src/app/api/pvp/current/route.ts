@@ -13,9 +13,9 @@ async function handler(req: NextRequest, ctx: { fid: number }): Promise<Response
     const st = await getSpacetime()
     const fid = ctx.fid
 
-    // Try to find latest active match for this user
+    // Try to find latest in-play match (including one whose result is being confirmed)
     const active = (await st.query(
-      `SELECT * FROM pvp_match WHERE (challenger_fid = ${fid} OR challenged_fid = ${fid}) AND status = 'active' ORDER BY accepted_at_ms DESC LIMIT 1`
+      `SELECT * FROM pvp_match WHERE (challenger_fid = ${fid} OR challenged_fid = ${fid}) AND (status = 'active' OR status = 'awaiting_confirmation' OR status = 'disputed') ORDER BY accepted_at_ms DESC LIMIT 1`
     )) as any[]
 
     let match = active?.[0]
@@ -44,6 +44,8 @@ async function handler(req: NextRequest, ctx: { fid: number }): Promise<Response
           status: match.status,
           createdAtMs: match.created_at_ms,
           acceptedAtMs: match.accepted_at_ms ?? null,
+          reportedByFid: match.reported_by_fid ?? null,
+          resultJson: match.result_json ?? null,
           pending,
         },
         opponent: { fid: opponentFid, players: opponentPlayers },
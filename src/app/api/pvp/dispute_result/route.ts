@@ -0,0 +1,19 @@
+import type { NextRequest } from 'next/server'
+import { requireAuth } from '@/lib/middleware/auth'
+import { stPvpDisputeResult } from '@/lib/spacetime/api'
+import { withErrorHandling, badRequest, ok } from '@/lib/api/http'
+
+export const runtime = 'nodejs'
+
+async function handler(req: NextRequest, ctx: { fid: number }): Promise<Response> {
+  return withErrorHandling(async () => {
+    const body = (await req.json()) as { matchId?: string; reason?: string }
+    const matchId = String(body?.matchId || '')
+    const reason = String(body?.reason || '').slice(0, 280)
+    if (!matchId) return badRequest('invalid_match')
+    await stPvpDisputeResult(matchId, ctx.fid, reason)
+    return ok({ ok: true })
+  })
+}
+
+export const POST = requireAuth(handler)
@@ -0,0 +1,23 @@
+import type { NextRequest } from 'next/server'
+import { requireAuth } from '@/lib/middleware/auth'
+import { stMatchmakingEnqueue, stMatchmakingLeave } from '@/lib/spacetime/api'
+import { withErrorHandling, ok } from '@/lib/api/http'
+
+export const runtime = 'nodejs'
+
+async function join(_req: NextRequest, ctx: { fid: number }): Promise<Response> {
+  return withErrorHandling(async () => {
+    await stMatchmakingEnqueue(ctx.fid)
+    return ok({ ok: true })
+  })
+}
+
+async function leave(_req: NextRequest, ctx: { fid: number }): Promise<Response> {
+  return withErrorHandling(async () => {
+    await stMatchmakingLeave(ctx.fid)
+    return ok({ ok: true })
+  })
+}
+
+export const POST = requireAuth(join)
+export const DELETE = requireAuth(leave)
@@ -10,7 +10,7 @@ import { fetchFarcasterUser, rankFromFollowers, intelligenceFromFollowers } from
 import { verifyFBCTransfer } from '@/lib/services/verification';
 import { validate, verifyStarterSchema } from '@/lib/middleware/validation';
 import { requireAuth, isDevFID } from '@/lib/middleware/auth';
-import { generateStarterPack, toUiPack } from '@/lib/starter/generate';
+import { toUiPack } from '@/lib/starter/generate';
 import { withErrorHandling, validateBody, ok, conflict, badRequest } from '@/lib/api/http';
 
 export const runtime = 'nodejs';
@@ -19,8 +19,6 @@ export const dynamic = 'force-dynamic';
 const TREASURY_ADDRESS = (process.env.NEXT_PUBLIC_TREASURY_ADDRESS as Address) || '0x0000000000000000000000000000000000000000';
 const STARTER_PRICE_USD = process.env.NEXT_PUBLIC_STARTER_PACK_PRICE_USD || '1';
 
-// generateStarterPack moved to shared util
-
 async function handler(req: NextRequest, ctx: { fid: number; wallet: string }): Promise<Response> {
   return withErrorHandling(async () => {
     const { fid, wallet } = ctx;
@@ -32,8 +30,7 @@ async function handler(req: NextRequest, ctx: { fid: number; wallet: string }):
     // - Admin wallet (treasury address)
     const isAdminWallet = (wallet || '').toLowerCase() === TREASURY_ADDRESS.toLowerCase();
     if (isDevFID(fid) || isAdminWallet) {
-      const players = generateStarterPack();
-      await stGrantStarterPack(fid, players);
+      const players = await stGrantStarterPack(fid);
       // Auto-mint NPC Squad for the user (from Neynar)
       try {
         const u = await fetchFarcasterUser(fid);
@@ -69,9 +66,8 @@ async function handler(req: NextRequest, ctx: { fid: number; wallet: string }):
     // Mark transaction as used to prevent replay
     await stMarkTxUsed(txHash, fid, '/api/starter/verify');
 
-    // Generate and grant pack via Spacetime
-    const players = generateStarterPack();
-    await stGrantStarterPack(fid, players);
+    // The module generates and grants the pack
+    const players = await stGrantStarterPack(fid);
 
     // Auto-mint NPC Squad for the user (from Neynar)
     try {
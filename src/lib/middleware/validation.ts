@@ -47,13 +47,35 @@ export const buyListingSchema = z.object({
   txHash: z.string().regex(/^0x[a-fA-F0-9]{64}$/),
 });
 
+export const cancelListingSchema = z.object({
+  listingId: z.string().uuid(),
+});
+
+export const updateListingPriceSchema = z.object({
+  listingId: z.string().uuid(),
+  priceFbcWei: z.string().regex(/^\d+$/),
+});
+
+export const reservePurchaseSchema = z.object({
+  listingId: z.string().uuid(),
+});
+
 // ========== AUCTIONS ==========
 
 export const createAuctionSchema = z.object({
   itemId: z.string().min(1),
   reserveFbcWei: z.string().regex(/^\d+$/),
-  durationH: z.number().int().min(1).max(168).default(48),
+  // Per-item-type bounds (e.g. longer minimums for squads) are enforced by the module
+  durationH: z.number().int().min(1).max(336).default(48),
   buyNowFbcWei: z.string().regex(/^\d+$/).optional(),
+  antiSnipe: z
+    .object({
+      window_seconds: z.number().int().min(0).max(3600).optional(),
+      extend_seconds: z.number().int().min(0).max(3600).optional(),
+      max_extensions: z.number().int().min(0).max(100).optional(),
+      max_total_extension_seconds: z.number().int().min(0).max(7 * 24 * 3600).nullable().optional(),
+    })
+    .optional(),
 });
 
 export const placeBidSchema = z.object({
@@ -61,16 +83,78 @@ export const placeBidSchema = z.object({
   amountFbcWei: z.string().regex(/^\d+$/),
 });
 
+export const setMaxBidSchema = z.object({
+  auctionId: z.string().uuid(),
+  maxFbcWei: z.string().regex(/^\d+$/),
+});
+
 export const finalizeAuctionSchema = z.object({
   auctionId: z.string().uuid(),
   txHash: z.string().regex(/^0x[a-fA-F0-9]{64}$/),
 });
 
+export const cancelAuctionSchema = z.object({
+  auctionId: z.string().uuid(),
+});
+
 export const buyNowAuctionSchema = z.object({
   auctionId: z.string().uuid(),
   txHash: z.string().regex(/^0x[a-fA-F0-9]{64}$/),
 });
 
+// ========== LINEUP ==========
+
+export const lineupFormationSchema = z.object({
+  formation: z.enum(['4-3-3', '4-4-2', '3-5-2', '4-2-3-1', '5-3-2', '3-4-3', '4-5-1']),
+});
+
+export const lineupAssignSchema = z.object({
+  slot: z.number().int().min(0).max(17),
+  itemId: z.string().min(1).nullable(),
+});
+
+export const lineupCaptainSchema = z.object({
+  itemId: z.string().min(1).nullable(),
+});
+
+// ========== LEAGUES ==========
+
+export const leagueCreateSchema = z.object({
+  pyramid: z.string().min(1).max(64),
+  name: z.string().min(1).max(64),
+  division: z.number().int().min(1),
+  promotionSlots: z.number().int().min(0).default(0),
+  relegationSlots: z.number().int().min(0).default(0),
+  memberFids: z.array(z.number().int().positive()).min(2).max(24),
+});
+
+export const seasonStartSchema = z.object({
+  pyramid: z.string().min(1).max(64),
+  mode: z.enum(['sim', 'played']).default('sim'),
+  roundIntervalHours: z.number().int().min(1).max(168).default(24),
+  firstKickoffMs: z.number().int().nonnegative().optional(),
+});
+
+// ========== CUPS ==========
+
+export const cupCreateSchema = z.object({
+  name: z.string().min(1).max(64),
+  entryFeeFbcWei: z.string().regex(/^\d+$/).default('0'),
+  maxEntrants: z.number().int().min(2).max(64),
+  groupStage: z.boolean().default(false),
+  // Champion, runner-up, semi-final losers, quarter-final losers; must sum to 10000
+  prizeSplitBps: z.array(z.number().int().min(0).max(10000)).max(4).default([]),
+});
+
+export const cupEnterSchema = z.object({
+  cupId: z.string().min(1),
+});
+
+export const cupAdminSchema = z.object({
+  cupId: z.string().min(1),
+  action: z.enum(['start', 'cancel']),
+});
+
 // ========== INBOX ==========
 
 export const markReadSchema = z.object({
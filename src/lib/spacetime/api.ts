@@ -1,4 +1,5 @@
 import type { Player } from '@/lib/types';
+import type { StarterPlayer } from '@/lib/starter/generate';
 import { reducers, getSpacetime } from './client';
 
 function idx(table: any, indexName: string): any {
@@ -11,6 +12,12 @@ function iso(ms: number | null | undefined): string | null {
   try { return new Date(ms!).toISOString(); } catch { return null; }
 }
 
+// Wei columns are u256 in the module (bigint in bindings); the API keeps decimal strings
+function weiStr(v: bigint | string | null | undefined): string | null {
+  if (v === null || v === undefined) return null;
+  return v.toString();
+}
+
 async function getReducer(name: string): Promise<(...args: any[]) => Promise<any>> {
   const r: any = await reducers();
   if (typeof r?.[name] === 'function') return r[name].bind(r);
@@ -32,11 +39,11 @@ async function callReducerCompat(nameSnake: string, argsPositional: any[], argsN
   const r: any = await reducers();
   const camel = toCamelCase(nameSnake);
   const i64Keys = new Set([
-    'fid', 'buyerFid', 'sellerFid', 'winnerFid', 'challengerFid', 'challengedFid', 'accepterFid', 'reporterFid',
+    'fid', 'buyerFid', 'sellerFid', 'winnerFid', 'challengerFid', 'challengedFid', 'accepterFid', 'reporterFid', 'requesterFid',
     // NPC & squad related
     'npcFid', 'userFid', 'aiSeed', 'sourceFid', 'followers', 'ownerFid',
     // Misc durations that some reducers model as i64
-    'durationSeconds', 'nextDecisionAtMs', 'tsMs'
+    'durationSeconds', 'nextDecisionAtMs', 'tsMs', 'roundIntervalHours', 'firstKickoffMs'
   ]);
   const orderedArgNames = Object.keys(argsNamed);
   const positional = argsPositional.map((v, i) => {
@@ -50,31 +57,84 @@ async function callReducerCompat(nameSnake: string, argsPositional: any[], argsN
   throw new Error(`Reducer ${nameSnake} not available`);
 }
 
-export async function stGetPlayersMine(fid: number): Promise<Player[]> {
+function cardToPlayer(card: any, item: any): Player {
+  return {
+    playerId: item.itemId,
+    ownerFid: Number(item.ownerFid),
+    name: card.name,
+    position: (card.positions?.[0] ?? 'MID') as Player['position'],
+    rating: Number(card.overall),
+    xp: 0,
+    morale: 70,
+    holdEnd: iso(Number(item.holdUntilMs)),
+    isNpc: false,
+    attributes: {
+      pace: Number(card.pace),
+      shooting: Number(card.shooting),
+      passing: Number(card.passing),
+      // Cards have no dribbling attribute; approximate it from pace and passing
+      dribbling: Math.round((Number(card.pace) + Number(card.passing)) / 2),
+      defending: Number(card.defending),
+      physical: Number(card.physical),
+    },
+  };
+}
+
+/** Canonical card for a player item (positions, attributes, potential), or null. */
+export async function stGetPlayerCard(itemId: string): Promise<any | null> {
   const st = await getSpacetime();
+  const c = st.db.playerCard.itemId().find(itemId) as any;
+  if (!c) return null;
+  return {
+    itemId: c.itemId,
+    name: c.name,
+    positions: Array.from(c.positions ?? []) as string[],
+    overall: Number(c.overall),
+    attributes: {
+      pace: Number(c.pace),
+      shooting: Number(c.shooting),
+      passing: Number(c.passing),
+      defending: Number(c.defending),
+      physical: Number(c.physical),
+      goalkeeping: Number(c.goalkeeping),
+    },
+    preferredFoot: c.preferredFoot,
+    potential: Number(c.potential),
+  };
+}
+
+/** Player items owned by `fid`, through the owner index when the SDK exposes it. */
+function ownedPlayerItems(st: any, fid: number): any[] {
   const fidBig = BigInt(fid);
-  // Stream inventory filtering to avoid array materialization
-  const inv: any[] = [];
-  for (const row of st.db.inventoryItem.iter() as Iterable<any>) {
-    if (row.ownerFid === fidBig && row.itemType === 'player') inv.push(row);
+  const index = idx(st.db.inventoryItem, 'ownerFid');
+  const rows: Iterable<any> = index?.filter ? index.filter(fidBig) : st.db.inventoryItem.iter();
+  const out: any[] = [];
+  for (const row of rows) {
+    if (row.ownerFid === fidBig && row.itemType === 'player') out.push(row);
   }
+  return out;
+}
 
-  // Stream events for starter pack metadata
-  const meta = new Map<string, any>();
-  for (const e of st.db.event.iter() as Iterable<any>) {
-    if (
-      e.actorFid === fidBig &&
-      (e.kind === 'StarterPackGranted' || e.kind === 'starter_pack_granted')
-    ) {
-      try {
-        const payload = JSON.parse(e.payloadJson);
-        for (const p of payload.players || []) meta.set(p.player_id, p);
-      } catch {}
-    }
+/** Player list from the `starter_pack_granted` event that minted `item`, if it did. */
+function starterPackPlayers(st: any, item: any): StarterPlayer[] | null {
+  const e = st.db.event.id().find(item.sourceEventId) as any;
+  if (!e || e.kind !== 'starter_pack_granted') return null;
+  try {
+    return (JSON.parse(e.payloadJson).players || []) as StarterPlayer[];
+  } catch {
+    return null;
   }
+}
+
+export async function stGetPlayersMine(fid: number): Promise<Player[]> {
+  const st = await getSpacetime();
+  const inv = ownedPlayerItems(st, fid);
 
   return inv.map((item) => {
-    const m = meta.get(item.itemId) || {};
+    // player_card is canonical; starter event metadata covers players minted before it
+    const card = st.db.playerCard.itemId().find(item.itemId) as any;
+    if (card) return cardToPlayer(card, item);
+    const m: any = starterPackPlayers(st, item)?.find((p) => p.player_id === item.itemId) || {};
     const name = m.name ?? `Player ${String(item.itemId).slice(0, 6)}`;
     const position = (m.position ?? 'MID') as Player['position'];
     const rating = Number(m.rating ?? 70);
@@ -115,7 +175,7 @@ export async function stListActiveListings(): Promise<any[]> {
       itemType,
       playerId: l.itemId,
       sellerFid: Number(l.sellerFid),
-      priceFbcWei: l.priceWei,
+      priceFbcWei: weiStr(l.priceWei),
       createdAt: iso(Number(l.createdAtMs)),
       status: 'active',
     };
@@ -130,12 +190,25 @@ export async function stGetListing(id: string): Promise<any | null> {
     id: l.id,
     playerId: l.itemId,
     sellerFid: Number(l.sellerFid),
-    priceFbcWei: l.priceWei,
+    priceFbcWei: weiStr(l.priceWei),
     createdAt: iso(Number(l.createdAtMs)),
     status: l.status === 'active' ? 'active' : 'sold',
   };
 }
 
+export async function stCancelListing(fid: number, listingId: string): Promise<void> {
+  await callReducerCompat('cancel_listing', [fid, listingId], { fid, listingId });
+}
+
+export async function stUpdateListingPrice(fid: number, listingId: string, priceFbcWei: string): Promise<void> {
+  await callReducerCompat('update_listing_price', [fid, listingId, priceFbcWei], { fid, listingId, priceWei: priceFbcWei });
+}
+
+// Freezes the listing price while the buyer sends the on-chain payment.
+export async function stListingReservePurchase(listingId: string, buyerFid: number): Promise<void> {
+  await callReducerCompat('listing_reserve_purchase', [listingId, buyerFid], { listingId, buyerFid });
+}
+
 export async function stCloseListingAndTransfer(listingId: string, buyerFid: number): Promise<void> {
   const r = await reducers();
   await r.close_listing_and_transfer(listingId, buyerFid);
@@ -147,7 +220,7 @@ export async function stListActiveAuctions(): Promise<any[]> {
     .filter((a) => a.status === 'active')
     .sort((a, b) => Number(b.createdAtMs - a.createdAtMs));
   return rows.map((a) => {
-    const currentBid = a.topBidWei ?? null;
+    const currentBid = weiStr(a.topBidWei);
     const incFloor = 1_000_000_000_000_000_000n;
     const minInc = BigInt(currentBid || '0') / 50n;
     const minIncWei = (minInc < incFloor ? incFloor : minInc).toString();
@@ -157,11 +230,12 @@ export async function stListActiveAuctions(): Promise<any[]> {
       sellerFid: Number(a.sellerFid),
       topBidFbcWei: currentBid,
       currentBidderFid: a.topBidderFid ? Number(a.topBidderFid) : null,
-      reserveFbcWei: a.reserveWei,
+      reserveFbcWei: weiStr(a.reserveWei),
       endsAt: iso(Number(a.endsAtMs)),
-      buyNowFbcWei: a.buyNowWei ?? null,
+      buyNowFbcWei: weiStr(a.buyNowWei),
       minIncrement: minIncWei,
       antiSnipeUsed: !!a.antiSnipeUsed,
+      antiSnipeExtendsBy: Number(a.antiSnipeExtendSeconds ?? 0),
       status: 'active',
     };
   });
@@ -188,9 +262,16 @@ export async function stHasEnteredBefore(fid: number): Promise<boolean> {
   return hasInventory || hasClaimed;
 }
 
-export async function stGrantStarterPack(fid: number, players: any[]): Promise<void> {
-  const playersJson = JSON.stringify({ players });
-  await callReducerCompat('grant_starter_pack', [fid, playersJson], { fid, playersJson });
+/** Grants the module-generated starter squad and returns it from the grant event. */
+export async function stGrantStarterPack(fid: number): Promise<StarterPlayer[]> {
+  await callReducerCompat('grant_starter_pack', [fid], { fid });
+  const st = await getSpacetime();
+  // The minted items point at their grant event; no need to scan the event log
+  for (const item of ownedPlayerItems(st, fid)) {
+    const players = starterPackPlayers(st, item);
+    if (players) return players;
+  }
+  return [];
 }
 
 export async function stCreateListing(fid: number, itemId: string, priceFbcWei: string): Promise<any> {
@@ -205,28 +286,37 @@ export async function stCreateListing(fid: number, itemId: string, priceFbcWei:
         id: l.id,
         playerId: l.itemId,
         sellerFid: Number(l.sellerFid),
-        priceFbcWei: l.priceWei,
+        priceFbcWei: weiStr(l.priceWei),
         createdAt: iso(Number(l.createdAtMs)),
         status: l.status,
       }
     : null;
 }
 
+/** Per-auction anti-snipe settings; omitted fields fall back to the module defaults. */
+export interface AntiSnipePolicy {
+  window_seconds?: number;
+  extend_seconds?: number;
+  max_extensions?: number;
+  max_total_extension_seconds?: number | null;
+}
+
 export async function stCreateAuction(
   fid: number,
   itemId: string,
   reserveFbcWei: string,
   durationSeconds: number,
-  buyNowFbcWei?: string | null
+  buyNowFbcWei?: string | null,
+  antiSnipe?: AntiSnipePolicy | null
 ): Promise<any> {
   const r = await reducers();
-  await r.create_auction(fid, itemId, reserveFbcWei, durationSeconds, buyNowFbcWei ?? null);
+  await r.create_auction(fid, itemId, reserveFbcWei, durationSeconds, buyNowFbcWei ?? null, antiSnipe ? JSON.stringify(antiSnipe) : null);
   const st = await getSpacetime();
   const a = (Array.from(st.db.auction.iter()) as any[])
     .filter((x) => x.sellerFid === BigInt(fid) && x.itemId === itemId)
     .sort((x, y) => Number(y.createdAtMs - x.createdAtMs))[0];
   if (!a) return null;
-  const currentBid = a.topBidWei ?? null;
+  const currentBid = weiStr(a.topBidWei);
   const minInc = BigInt(currentBid || '0') / 50n;
   const minIncWei = (minInc < 1_000_000_000_000_000_000n ? 1_000_000_000_000_000_000n : minInc).toString();
   return {
@@ -235,11 +325,12 @@ export async function stCreateAuction(
     sellerFid: Number(a.sellerFid),
     topBidFbcWei: currentBid,
     currentBidderFid: a.topBidderFid ? Number(a.topBidderFid) : null,
-    reserveFbcWei: a.reserveWei,
+    reserveFbcWei: weiStr(a.reserveWei),
     endsAt: iso(Number(a.endsAtMs)),
-    buyNowFbcWei: a.buyNowWei ?? null,
+    buyNowFbcWei: weiStr(a.buyNowWei),
     minIncrement: minIncWei,
     antiSnipeUsed: !!a.antiSnipeUsed,
+    antiSnipeExtendsBy: Number(a.antiSnipeExtendSeconds ?? 0),
     status: 'active',
   };
 }
@@ -256,6 +347,11 @@ export async function stPlaceBid(auctionId: string, fid: number, amountFbcWei: s
   return 'bid_placed';
 }
 
+/** Registers a private maximum bid; the module auto-bids up to it. */
+export async function stSetMaxBid(auctionId: string, fid: number, maxFbcWei: string): Promise<void> {
+  await callReducerCompat('set_max_bid', [fid, auctionId, maxFbcWei], { fid, auctionId, maxWei: maxFbcWei });
+}
+
 export async function stBuyNow(auctionId: string, buyerFid: number, buyNowFbcWei: string): Promise<void> {
   const r = await reducers();
   await r.buy_now(auctionId, buyerFid, buyNowFbcWei);
@@ -283,26 +379,32 @@ export async function stFinalizeAuction(auctionId: string, winnerFid: number): P
   await r.finalize_auction(auctionId, winnerFid);
 }
 
+export async function stCancelAuction(fid: number, auctionId: string): Promise<void> {
+  await callReducerCompat('cancel_auction', [fid, auctionId], { fid, auctionId });
+}
+
 export async function stGetAuction(auctionId: string): Promise<any | null> {
   const st = await getSpacetime();
   const a = st.db.auction.id().find(auctionId) as any;
   if (!a) return null;
-  const currentBid = a.topBidWei ?? null;
+  const currentBid = weiStr(a.topBidWei);
   const minInc = BigInt(currentBid || '0') / 50n;
   const minIncWei = (minInc < 1_000_000_000_000_000_000n ? 1_000_000_000_000_000_000n : minInc).toString();
   const now = Date.now();
-  const status = a.status === 'finalized' ? 'finalized' : now > Number(a.endsAtMs ?? 0n) ? 'awaiting_payment' : 'active';
+  // Terminal statuses (finalized, expired, reserve_not_met, cancelled) pass through
+  const status = a.status !== 'active' ? a.status : now > Number(a.endsAtMs ?? 0n) ? 'awaiting_payment' : 'active';
   return {
     id: a.id,
     playerId: a.itemId,
     sellerFid: Number(a.sellerFid),
     topBidFbcWei: currentBid,
     currentBidderFid: a.topBidderFid ? Number(a.topBidderFid) : null,
-    reserveFbcWei: a.reserveWei,
+    reserveFbcWei: weiStr(a.reserveWei),
     endsAt: iso(Number(a.endsAtMs)),
-    buyNowFbcWei: a.buyNowWei ?? null,
+    buyNowFbcWei: weiStr(a.buyNowWei),
     minIncrement: minIncWei,
     antiSnipeUsed: !!a.antiSnipeUsed,
+    antiSnipeExtendsBy: Number(a.antiSnipeExtendSeconds ?? 0),
     status,
   };
 }
@@ -423,7 +525,7 @@ export async function stNpcUpdateState(npcFid: number, nextDecisionAtMs: number,
 function mapNpcRow(n: any): any {
   return {
     npcFid: Number(n.npcFid),
-    tokenId: n.tokenId ?? null,
+    tokenId: n.tokenId || null,
     aiSeed: Number(n.aiSeed),
     difficultyTier: Number(n.difficultyTier),
     budgetFbcWei: String(n.budgetFbcWei),
@@ -518,14 +620,9 @@ export async function stPlayerStateApplyMatch(playerId: string, minutesPlayed: n
   });
 }
 
-export async function stPlayerStateRecoverTick(nowMs: number): Promise<void> {
-  await callReducerCompat('player_state_recover_tick', [nowMs], { nowMs });
-}
-
-export async function stPlayerAgeTick(): Promise<void> {
-  const r = await reducers() as any;
-  if (typeof r.player_age_tick === 'function') return r.player_age_tick();
-  await callReducerCompat('player_age_tick', [], {});
+// Recovery and aging run on module schedules; this only (re)arms them and is idempotent.
+export async function stPlayerTicksSchedule(): Promise<void> {
+  await callReducerCompat('player_ticks_schedule', [], {});
 }
 
 // Officials
@@ -594,3 +691,267 @@ export async function stPvpSubmitResult(matchId: string, reporterFid: number, re
   const r = await reducers();
   await r.pvp_submit_result(matchId, reporterFid, JSON.stringify(result));
 }
+
+export async function stPvpCancelChallenge(matchId: string, challengerFid: number): Promise<void> {
+  await callReducerCompat('pvp_cancel_challenge', [matchId, challengerFid], { matchId, challengerFid });
+}
+
+export async function stPvpDeclineChallenge(matchId: string, challengedFid: number): Promise<void> {
+  await callReducerCompat('pvp_decline_challenge', [matchId, challengedFid], { matchId, challengedFid });
+}
+
+export async function stPvpConfirmResult(matchId: string, fid: number): Promise<void> {
+  await callReducerCompat('pvp_confirm_result', [matchId, fid], { matchId, fid });
+}
+
+export async function stPvpDisputeResult(matchId: string, fid: number, reason: string): Promise<void> {
+  await callReducerCompat('pvp_dispute_result', [matchId, fid, reason], { matchId, fid, reason });
+}
+
+// Operator resolution; omit the result to let the module simulate the match.
+export async function stPvpResolveDispute(matchId: string, result?: any): Promise<void> {
+  const resultJson = result === undefined ? null : JSON.stringify(result);
+  await callReducerCompat('pvp_resolve_dispute', [matchId, resultJson], { matchId, resultJson });
+}
+
+// Plays the match in the module and finalizes it with the simulated result.
+export async function stPvpSimulateMatch(matchId: string, requesterFid: number): Promise<void> {
+  await callReducerCompat('pvp_simulate_match', [matchId, requesterFid], { matchId, requesterFid });
+}
+
+// Matchmaking queue; pairing happens on a module schedule.
+export async function stMatchmakingEnqueue(fid: number): Promise<void> {
+  await callReducerCompat('matchmaking_enqueue', [fid], { fid });
+}
+
+export async function stMatchmakingLeave(fid: number): Promise<void> {
+  await callReducerCompat('matchmaking_leave', [fid], { fid });
+}
+
+// ========== LEAGUES ==========
+
+export async function stLeagueCreate(
+  pyramid: string,
+  name: string,
+  division: number,
+  promotionSlots: number,
+  relegationSlots: number,
+  memberFids: number[],
+): Promise<void> {
+  const fids = memberFids.map((f) => BigInt(f));
+  await callReducerCompat('league_create', [pyramid, name, division, promotionSlots, relegationSlots, fids], {
+    pyramid, name, division, promotionSlots, relegationSlots, memberFids: fids,
+  });
+}
+
+export async function stSeasonStart(pyramid: string, mode: 'sim' | 'played', roundIntervalHours: number, firstKickoffMs: number): Promise<void> {
+  await callReducerCompat('season_start', [pyramid, mode, roundIntervalHours, firstKickoffMs], {
+    pyramid, mode, roundIntervalHours, firstKickoffMs,
+  });
+}
+
+function mapStanding(s: any, position: number) {
+  return {
+    position,
+    fid: Number(s.fid),
+    played: Number(s.played),
+    won: Number(s.won),
+    drawn: Number(s.drawn),
+    lost: Number(s.lost),
+    goalsFor: Number(s.goalsFor),
+    goalsAgainst: Number(s.goalsAgainst),
+    goalDifference: Number(s.goalsFor) - Number(s.goalsAgainst),
+    points: Number(s.points),
+    form: s.form as string,
+  };
+}
+
+export async function stListLeagues(): Promise<any[]> {
+  const st = await getSpacetime();
+  const leagues = Array.from(st.db.league.iter()) as any[];
+  const members = Array.from(st.db.leagueMember.iter()) as any[];
+  return leagues
+    .sort((a, b) => a.pyramid.localeCompare(b.pyramid) || a.division - b.division)
+    .map((l) => ({
+      id: l.id,
+      pyramid: l.pyramid,
+      name: l.name,
+      division: Number(l.division),
+      members: members.filter((m) => m.leagueId === l.id).length,
+    }));
+}
+
+/** League table and fixtures for the pyramid's active season (or its latest). */
+export async function stGetLeague(leagueId: string): Promise<any | null> {
+  const st = await getSpacetime();
+  const l = st.db.league.id().find(leagueId) as any;
+  if (!l) return null;
+  const seasons = (Array.from(st.db.season.iter()) as any[])
+    .filter((s) => s.pyramid === l.pyramid)
+    .sort((a, b) => b.number - a.number);
+  const season = seasons.find((s) => s.status === 'active') ?? seasons[0] ?? null;
+  const standings = season
+    ? (Array.from(st.db.standing.iter()) as any[])
+        .filter((s) => s.seasonId === season.id && s.leagueId === leagueId)
+        .sort((a, b) => (
+          b.points - a.points ||
+          Number(b.goalsFor - b.goalsAgainst) - Number(a.goalsFor - a.goalsAgainst) ||
+          Number(b.goalsFor - a.goalsFor) ||
+          Number(a.fid - b.fid)
+        ))
+        .map((s, i) => mapStanding(s, i + 1))
+    : [];
+  const fixtures = season
+    ? (Array.from(st.db.fixture.iter()) as any[])
+        .filter((f) => f.seasonId === season.id && f.leagueId === leagueId)
+        .sort((a, b) => a.round - b.round || Number(a.kickoffAtMs - b.kickoffAtMs))
+        .map((f) => ({
+          id: f.id,
+          round: Number(f.round),
+          homeFid: Number(f.homeFid),
+          awayFid: Number(f.awayFid),
+          kickoffAt: iso(Number(f.kickoffAtMs)),
+          status: f.status,
+          matchId: f.matchId || null,
+          homeGoals: f.homeGoals == null ? null : Number(f.homeGoals),
+          awayGoals: f.awayGoals == null ? null : Number(f.awayGoals),
+        }))
+    : [];
+  return {
+    id: l.id,
+    pyramid: l.pyramid,
+    name: l.name,
+    division: Number(l.division),
+    promotionSlots: Number(l.promotionSlots),
+    relegationSlots: Number(l.relegationSlots),
+    season: season ? { id: season.id, number: Number(season.number), status: season.status, mode: season.mode } : null,
+    standings,
+    fixtures,
+  };
+}
+
+export async function stGetLineup(fid: number): Promise<any> {
+  const st = await getSpacetime();
+  const fidBig = BigInt(fid);
+  const l = st.db.lineup.fid().find(fidBig) as any;
+  const slots = (Array.from(st.db.lineupSlot.iter()) as any[])
+    .filter((s) => s.fid === fidBig)
+    .sort((a, b) => a.slot - b.slot)
+    .map((s) => ({ slot: Number(s.slot), position: s.position, itemId: s.itemId }));
+  return {
+    formation: l?.formation ?? '4-4-2',
+    captainItemId: l?.captainItemId || null,
+    starters: slots.filter((s) => s.position !== 'SUB'),
+    subs: slots.filter((s) => s.position === 'SUB'),
+  };
+}
+
+export async function stLineupSetFormation(fid: number, formation: string): Promise<void> {
+  await callReducerCompat('lineup_set_formation', [fid, formation], { fid, formation });
+}
+
+/** Assigns a player to a slot (0-10 starters, 11+ subs); a null itemId clears it. */
+export async function stLineupAssign(fid: number, slot: number, itemId: string | null): Promise<void> {
+  await callReducerCompat('lineup_assign', [fid, slot, itemId ?? ''], { fid, slot, itemId: itemId ?? '' });
+}
+
+export async function stLineupSetCaptain(fid: number, itemId: string | null): Promise<void> {
+  await callReducerCompat('lineup_set_captain', [fid, itemId ?? ''], { fid, itemId: itemId ?? '' });
+}
+
+// ========== CUPS ==========
+
+/** Creates a cup open for entries; an empty prize split uses the module default (70/30). */
+export async function stCupCreate(
+  name: string,
+  entryFeeWei: string,
+  maxEntrants: number,
+  groupStage: boolean,
+  prizeSplitBps: number[],
+): Promise<void> {
+  await callReducerCompat('cup_create', [name, entryFeeWei, maxEntrants, groupStage, prizeSplitBps], {
+    name, entryFeeWei, maxEntrants, groupStage, prizeSplitBps,
+  });
+}
+
+// The entry fee is taken from the manager's available FBC balance.
+export async function stCupEnter(fid: number, cupId: string): Promise<void> {
+  await callReducerCompat('cup_enter', [fid, cupId], { fid, cupId });
+}
+
+export async function stCupStart(cupId: string): Promise<void> {
+  await callReducerCompat('cup_start', [cupId], { cupId });
+}
+
+// Registration only; every entry fee is refunded.
+export async function stCupCancel(cupId: string): Promise<void> {
+  await callReducerCompat('cup_cancel', [cupId], { cupId });
+}
+
+function mapCup(c: any, entrants: number) {
+  return {
+    id: c.id,
+    name: c.name,
+    status: c.status,
+    entryFeeWei: String(c.entryFeeWei),
+    prizePoolWei: String(c.prizePoolWei),
+    maxEntrants: Number(c.maxEntrants),
+    groupStage: Boolean(c.groupStage),
+    prizeSplitBps: (c.prizeSplitBps as any[]).map(Number),
+    knockoutRounds: Number(c.knockoutRounds),
+    currentRound: Number(c.currentRound),
+    winnerFid: c.winnerFid == null ? null : Number(c.winnerFid),
+    entrants,
+    createdAt: iso(Number(c.createdAtMs)),
+  };
+}
+
+export async function stListCups(): Promise<any[]> {
+  const st = await getSpacetime();
+  const entries = Array.from(st.db.cupEntry.iter()) as any[];
+  return (Array.from(st.db.cup.iter()) as any[])
+    .sort((a, b) => Number(b.createdAtMs - a.createdAtMs))
+    .map((c) => mapCup(c, entries.filter((e) => e.cupId === c.id).length));
+}
+
+/** Cup details with entrants, group fixtures and the knockout bracket by round. */
+export async function stGetCup(cupId: string): Promise<any | null> {
+  const st = await getSpacetime();
+  const c = st.db.cup.id().find(cupId) as any;
+  if (!c) return null;
+  const entries = (Array.from(st.db.cupEntry.iter()) as any[])
+    .filter((e) => e.cupId === cupId)
+    .sort((a, b) => a.seed - b.seed || Number(a.enteredAtMs - b.enteredAtMs))
+    .map((e) => ({
+      fid: Number(e.fid),
+      seed: Number(e.seed) || null,
+      group: e.groupName || null,
+      status: e.status,
+      eliminatedRound: e.eliminatedRound == null ? null : Number(e.eliminatedRound),
+    }));
+  const ties = (Array.from(st.db.cupTie.iter()) as any[])
+    .filter((t) => t.cupId === cupId)
+    .sort((a, b) => a.round - b.round || a.slot - b.slot)
+    .map((t) => ({
+      id: t.id,
+      stage: t.stage,
+      round: Number(t.round),
+      slot: Number(t.slot),
+      group: t.groupName || null,
+      homeFid: Number(t.homeFid),
+      awayFid: t.awayFid == null ? null : Number(t.awayFid),
+      status: t.status,
+      matchId: t.matchId || null,
+      homeGoals: t.homeGoals == null ? null : Number(t.homeGoals),
+      awayGoals: t.awayGoals == null ? null : Number(t.awayGoals),
+      penalties: t.penaltiesJson ? JSON.parse(t.penaltiesJson) : null,
+      decidedBy: t.decidedBy || null,
+      winnerFid: t.winnerFid == null ? null : Number(t.winnerFid),
+    }));
+  return {
+    ...mapCup(c, entries.length),
+    entries,
+    groups: ties.filter((t) => t.stage === 'group'),
+    bracket: ties.filter((t) => t.stage === 'knockout'),
+  };
+}
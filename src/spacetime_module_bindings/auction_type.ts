@@ -14,15 +14,20 @@ export default __t.object("Auction", {
   id: __t.string(),
   itemId: __t.string(),
   sellerFid: __t.i64(),
-  reserveWei: __t.string(),
+  reserveWei: __t.u256(),
   endsAtMs: __t.i64(),
   status: __t.string(),
-  topBidWei: __t.option(__t.string()),
+  topBidWei: __t.option(__t.u256()),
   topBidderFid: __t.option(__t.i64()),
-  buyNowWei: __t.option(__t.string()),
+  buyNowWei: __t.option(__t.u256()),
   antiSnipeUsed: __t.bool(),
   createdAtMs: __t.i64(),
   finalizedAtMs: __t.option(__t.i64()),
+  antiSnipeWindowSeconds: __t.i64(),
+  antiSnipeExtendSeconds: __t.i64(),
+  antiSnipeMaxExtensions: __t.i32(),
+  antiSnipeExtensions: __t.i32(),
+  maxEndsAtMs: __t.option(__t.i64()),
 });
 
 
@@ -14,6 +14,6 @@ export default __t.row({
   id: __t.string().primaryKey(),
   auctionId: __t.string(),
   fid: __t.i64(),
-  amountWei: __t.string(),
+  amountWei: __t.u256(),
   placedAtMs: __t.i64(),
 });
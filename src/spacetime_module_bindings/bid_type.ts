@@ -14,7 +14,7 @@ export default __t.object("Bid", {
   id: __t.string(),
   auctionId: __t.string(),
   fid: __t.i64(),
-  amountWei: __t.string(),
+  amountWei: __t.u256(),
   placedAtMs: __t.i64(),
 });
 
@@ -16,4 +16,5 @@ export default {
   reserveWei: __t.string(),
   durationSeconds: __t.i64(),
   buyNowWei: __t.option(__t.string()),
+  antiSnipeJson: __t.option(__t.string()),
 };
@@ -15,7 +15,7 @@ export default __t.object("Event", {
   tsMs: __t.i64(),
   kind: __t.string(),
   actorFid: __t.i64(),
-  topicId: __t.option(__t.string()),
+  topicId: __t.string(),
   payloadJson: __t.string(),
 });
 
@@ -12,5 +12,4 @@ import {
 
 export default {
   fid: __t.i64(),
-  playersJson: __t.string(),
 };
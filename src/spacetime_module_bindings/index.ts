@@ -33,8 +33,18 @@ import {
 // Import and reexport all reducer arg types
 import AuctionBuyNowApply from "./auction_buy_now_apply_reducer";
 export { AuctionBuyNowApply };
+import AuctionCloseTick from "./auction_close_tick_reducer";
+export { AuctionCloseTick };
+import BalanceDeposit from "./balance_deposit_reducer";
+export { BalanceDeposit };
+import BalanceWithdraw from "./balance_withdraw_reducer";
+export { BalanceWithdraw };
 import BuyNow from "./buy_now_reducer";
 export { BuyNow };
+import CancelAuction from "./cancel_auction_reducer";
+export { CancelAuction };
+import CancelListing from "./cancel_listing_reducer";
+export { CancelListing };
 import CloseListingAndTransfer from "./close_listing_and_transfer_reducer";
 export { CloseListingAndTransfer };
 import CommentaryAppend from "./commentary_append_reducer";
@@ -43,18 +53,52 @@ import CreateAuction from "./create_auction_reducer";
 export { CreateAuction };
 import CreateListing from "./create_listing_reducer";
 export { CreateListing };
+import CupCancel from "./cup_cancel_reducer";
+export { CupCancel };
+import CupCreate from "./cup_create_reducer";
+export { CupCreate };
+import CupEnter from "./cup_enter_reducer";
+export { CupEnter };
+import CupStart from "./cup_start_reducer";
+export { CupStart };
 import FinalizeAuction from "./finalize_auction_reducer";
 export { FinalizeAuction };
+import FixtureKickoffTick from "./fixture_kickoff_tick_reducer";
+export { FixtureKickoffTick };
 import GrantStarterPack from "./grant_starter_pack_reducer";
 export { GrantStarterPack };
+import HoldExemptionSet from "./hold_exemption_set_reducer";
+export { HoldExemptionSet };
+import IdentitySignIn from "./identity_sign_in_reducer";
+export { IdentitySignIn };
+import IdentityUnlink from "./identity_unlink_reducer";
+export { IdentityUnlink };
 import InboxMarkRead from "./inbox_mark_read_reducer";
 export { InboxMarkRead };
+import LeagueCreate from "./league_create_reducer";
+export { LeagueCreate };
+import LineupAssign from "./lineup_assign_reducer";
+export { LineupAssign };
+import LineupSetCaptain from "./lineup_set_captain_reducer";
+export { LineupSetCaptain };
+import LineupSetFormation from "./lineup_set_formation_reducer";
+export { LineupSetFormation };
 import LinkWallet from "./link_wallet_reducer";
 export { LinkWallet };
+import ListingReservePurchase from "./listing_reserve_purchase_reducer";
+export { ListingReservePurchase };
 import MarkTxUsed from "./mark_tx_used_reducer";
 export { MarkTxUsed };
+import MarketLimitsSet from "./market_limits_set_reducer";
+export { MarketLimitsSet };
 import MarketplacePurchaseApply from "./marketplace_purchase_apply_reducer";
 export { MarketplacePurchaseApply };
+import MatchmakingEnqueue from "./matchmaking_enqueue_reducer";
+export { MatchmakingEnqueue };
+import MatchmakingLeave from "./matchmaking_leave_reducer";
+export { MatchmakingLeave };
+import MatchmakingTick from "./matchmaking_tick_reducer";
+export { MatchmakingTick };
 import NpcAssignForUser from "./npc_assign_for_user_reducer";
 export { NpcAssignForUser };
 import NpcCreate from "./npc_create_reducer";
@@ -71,6 +115,10 @@ import OfficialSetActive from "./official_set_active_reducer";
 export { OfficialSetActive };
 import OfficialUpdateAfterMatch from "./official_update_after_match_reducer";
 export { OfficialUpdateAfterMatch };
+import OperatorGrant from "./operator_grant_reducer";
+export { OperatorGrant };
+import OperatorRevoke from "./operator_revoke_reducer";
+export { OperatorRevoke };
 import PlaceBid from "./place_bid_reducer";
 export { PlaceBid };
 import PlayerAgeTick from "./player_age_tick_reducer";
@@ -81,14 +129,40 @@ import PlayerStateApplyMatch from "./player_state_apply_match_reducer";
 export { PlayerStateApplyMatch };
 import PlayerStateRecoverTick from "./player_state_recover_tick_reducer";
 export { PlayerStateRecoverTick };
+import PlayerTicksSchedule from "./player_ticks_schedule_reducer";
+export { PlayerTicksSchedule };
 import PvpAccept from "./pvp_accept_reducer";
 export { PvpAccept };
+import PvpCancelChallenge from "./pvp_cancel_challenge_reducer";
+export { PvpCancelChallenge };
+import PvpConfigSet from "./pvp_config_set_reducer";
+export { PvpConfigSet };
+import PvpConfirmResult from "./pvp_confirm_result_reducer";
+export { PvpConfirmResult };
+import PvpConfirmTimeoutTick from "./pvp_confirm_timeout_tick_reducer";
+export { PvpConfirmTimeoutTick };
 import PvpCreateChallenge from "./pvp_create_challenge_reducer";
 export { PvpCreateChallenge };
+import PvpDeclineChallenge from "./pvp_decline_challenge_reducer";
+export { PvpDeclineChallenge };
+import PvpDisputeResult from "./pvp_dispute_result_reducer";
+export { PvpDisputeResult };
+import PvpMatchTimeoutTick from "./pvp_match_timeout_tick_reducer";
+export { PvpMatchTimeoutTick };
+import PvpResolveDispute from "./pvp_resolve_dispute_reducer";
+export { PvpResolveDispute };
+import PvpSimulateMatch from "./pvp_simulate_match_reducer";
+export { PvpSimulateMatch };
 import PvpSubmitResult from "./pvp_submit_result_reducer";
 export { PvpSubmitResult };
+import SeasonStart from "./season_start_reducer";
+export { SeasonStart };
+import SetMaxBid from "./set_max_bid_reducer";
+export { SetMaxBid };
 import SquadMintFromFarcaster from "./squad_mint_from_farcaster_reducer";
 export { SquadMintFromFarcaster };
+import UpdateListingPrice from "./update_listing_price_reducer";
+export { UpdateListingPrice };
 import VarReviewRecord from "./var_review_record_reducer";
 export { VarReviewRecord };
 
@@ -97,34 +171,98 @@ export { VarReviewRecord };
 // Import and reexport all table handle types
 import AuctionRow from "./auction_table";
 export { AuctionRow };
+import AuctionTimerRow from "./auction_timer_table";
+export { AuctionTimerRow };
+import BalanceRow from "./balance_table";
+export { BalanceRow };
 import BidRow from "./bid_table";
 export { BidRow };
 import CommentaryLogRow from "./commentary_log_table";
 export { CommentaryLogRow };
+import CupRow from "./cup_table";
+export { CupRow };
+import CupEntryRow from "./cup_entry_table";
+export { CupEntryRow };
+import CupTieRow from "./cup_tie_table";
+export { CupTieRow };
 import EventRow from "./event_table";
 export { EventRow };
+import FixtureRow from "./fixture_table";
+export { FixtureRow };
+import FixtureKickoffRow from "./fixture_kickoff_table";
+export { FixtureKickoffRow };
+import HoldExemptionRow from "./hold_exemption_table";
+export { HoldExemptionRow };
 import IdempotencyRow from "./idempotency_table";
 export { IdempotencyRow };
+import IdentityLinkRow from "./identity_link_table";
+export { IdentityLinkRow };
 import InboxRow from "./inbox_table";
 export { InboxRow };
 import InventoryItemRow from "./inventory_item_table";
 export { InventoryItemRow };
+import ItemLockRow from "./item_lock_table";
+export { ItemLockRow };
+import LeagueRow from "./league_table";
+export { LeagueRow };
+import LeagueMemberRow from "./league_member_table";
+export { LeagueMemberRow };
+import LedgerEntryRow from "./ledger_entry_table";
+export { LedgerEntryRow };
+import LineupRow from "./lineup_table";
+export { LineupRow };
+import LineupSlotRow from "./lineup_slot_table";
+export { LineupSlotRow };
 import ListingRow from "./listing_table";
 export { ListingRow };
+import ListingReservationRow from "./listing_reservation_table";
+export { ListingReservationRow };
+import MarketLimitsRow from "./market_limits_table";
+export { MarketLimitsRow };
 import MatchOfficialAssignmentRow from "./match_official_assignment_table";
 export { MatchOfficialAssignmentRow };
+import MatchmakingQueueRow from "./matchmaking_queue_table";
+export { MatchmakingQueueRow };
+import MatchmakingScheduleRow from "./matchmaking_schedule_table";
+export { MatchmakingScheduleRow };
 import NpcAssignmentRow from "./npc_assignment_table";
 export { NpcAssignmentRow };
 import NpcRegistryRow from "./npc_registry_table";
 export { NpcRegistryRow };
 import OfficialsRow from "./officials_table";
 export { OfficialsRow };
+import OperatorRoleRow from "./operator_role_table";
+export { OperatorRoleRow };
+import PlayerAgeScheduleRow from "./player_age_schedule_table";
+export { PlayerAgeScheduleRow };
+import PlayerCardRow from "./player_card_table";
+export { PlayerCardRow };
+import PlayerMatchLogRow from "./player_match_log_table";
+export { PlayerMatchLogRow };
+import PlayerRecoverScheduleRow from "./player_recover_schedule_table";
+export { PlayerRecoverScheduleRow };
 import PlayerStateRow from "./player_state_table";
 export { PlayerStateRow };
+import ProxyBidRow from "./proxy_bid_table";
+export { ProxyBidRow };
+import PvpConfigRow from "./pvp_config_table";
+export { PvpConfigRow };
+import PvpConfirmTimerRow from "./pvp_confirm_timer_table";
+export { PvpConfirmTimerRow };
+import PvpDisputeRow from "./pvp_dispute_table";
+export { PvpDisputeRow };
 import PvpMatchRow from "./pvp_match_table";
 export { PvpMatchRow };
+import PvpMatchTimerRow from "./pvp_match_timer_table";
+export { PvpMatchTimerRow };
+import RatingHistoryRow from "./rating_history_table";
+export { RatingHistoryRow };
+import SeasonRow from "./season_table";
+export { SeasonRow };
 import SquadRegistryRow from "./squad_registry_table";
 export { SquadRegistryRow };
+import StandingRow from "./standing_table";
+export { StandingRow };
 import StarterClaimRow from "./starter_claim_table";
 export { StarterClaimRow };
 import TransactionUsedRow from "./transaction_used_table";
@@ -137,34 +275,98 @@ export { WalletLinkRow };
 // Import and reexport all types
 import Auction from "./auction_type";
 export { Auction };
+import AuctionTimer from "./auction_timer_type";
+export { AuctionTimer };
+import Balance from "./balance_type";
+export { Balance };
 import Bid from "./bid_type";
 export { Bid };
 import CommentaryLog from "./commentary_log_type";
 export { CommentaryLog };
+import Cup from "./cup_type";
+export { Cup };
+import CupEntry from "./cup_entry_type";
+export { CupEntry };
+import CupTie from "./cup_tie_type";
+export { CupTie };
 import Event from "./event_type";
 export { Event };
+import Fixture from "./fixture_type";
+export { Fixture };
+import FixtureKickoff from "./fixture_kickoff_type";
+export { FixtureKickoff };
+import HoldExemption from "./hold_exemption_type";
+export { HoldExemption };
 import Idempotency from "./idempotency_type";
 export { Idempotency };
+import IdentityLink from "./identity_link_type";
+export { IdentityLink };
 import Inbox from "./inbox_type";
 export { Inbox };
 import InventoryItem from "./inventory_item_type";
 export { InventoryItem };
+import ItemLock from "./item_lock_type";
+export { ItemLock };
+import League from "./league_type";
+export { League };
+import LeagueMember from "./league_member_type";
+export { LeagueMember };
+import LedgerEntry from "./ledger_entry_type";
+export { LedgerEntry };
+import Lineup from "./lineup_type";
+export { Lineup };
+import LineupSlot from "./lineup_slot_type";
+export { LineupSlot };
 import Listing from "./listing_type";
 export { Listing };
+import ListingReservation from "./listing_reservation_type";
+export { ListingReservation };
+import MarketLimits from "./market_limits_type";
+export { MarketLimits };
 import MatchOfficialAssignment from "./match_official_assignment_type";
 export { MatchOfficialAssignment };
+import MatchmakingQueue from "./matchmaking_queue_type";
+export { MatchmakingQueue };
+import MatchmakingSchedule from "./matchmaking_schedule_type";
+export { MatchmakingSchedule };
 import NpcAssignment from "./npc_assignment_type";
 export { NpcAssignment };
 import NpcRegistry from "./npc_registry_type";
 export { NpcRegistry };
 import Official from "./official_type";
 export { Official };
+import OperatorRole from "./operator_role_type";
+export { OperatorRole };
+import PlayerAgeSchedule from "./player_age_schedule_type";
+export { PlayerAgeSchedule };
+import PlayerCard from "./player_card_type";
+export { PlayerCard };
+import PlayerMatchLog from "./player_match_log_type";
+export { PlayerMatchLog };
+import PlayerRecoverSchedule from "./player_recover_schedule_type";
+export { PlayerRecoverSchedule };
 import PlayerState from "./player_state_type";
 export { PlayerState };
+import ProxyBid from "./proxy_bid_type";
+export { ProxyBid };
+import PvpConfig from "./pvp_config_type";
+export { PvpConfig };
+import PvpConfirmTimer from "./pvp_confirm_timer_type";
+export { PvpConfirmTimer };
+import PvpDispute from "./pvp_dispute_type";
+export { PvpDispute };
 import PvpMatch from "./pvp_match_type";
 export { PvpMatch };
+import PvpMatchTimer from "./pvp_match_timer_type";
+export { PvpMatchTimer };
+import RatingHistory from "./rating_history_type";
+export { RatingHistory };
+import Season from "./season_type";
+export { Season };
 import SquadRegistry from "./squad_registry_type";
 export { SquadRegistry };
+import Standing from "./standing_type";
+export { Standing };
 import StarterClaim from "./starter_claim_type";
 export { StarterClaim };
 import TransactionUsed from "./transaction_used_type";
@@ -187,12 +389,37 @@ const tablesSchema = __schema(
       { name: 'auction_id_key', constraint: 'unique', columns: ['id'] },
     ],
   }, AuctionRow),
+  __table({
+    name: 'auction_timer',
+    indexes: [
+      { name: 'scheduled_id', algorithm: 'btree', columns: [
+        'scheduledId',
+      ] },
+    ],
+    constraints: [
+      { name: 'auction_timer_scheduled_id_key', constraint: 'unique', columns: ['scheduledId'] },
+    ],
+  }, AuctionTimerRow),
+  __table({
+    name: 'balance',
+    indexes: [
+      { name: 'fid', algorithm: 'btree', columns: [
+        'fid',
+      ] },
+    ],
+    constraints: [
+      { name: 'balance_fid_key', constraint: 'unique', columns: ['fid'] },
+    ],
+  }, BalanceRow),
   __table({
     name: 'bid',
     indexes: [
       { name: 'id', algorithm: 'btree', columns: [
         'id',
       ] },
+      { name: 'auction_id', algorithm: 'btree', columns: [
+        'auctionId',
+      ] },
     ],
     constraints: [
       { name: 'bid_id_key', constraint: 'unique', columns: ['id'] },
@@ -209,17 +436,101 @@ const tablesSchema = __schema(
       { name: 'commentary_log_id_key', constraint: 'unique', columns: ['id'] },
     ],
   }, CommentaryLogRow),
+  __table({
+    name: 'cup',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+    ],
+    constraints: [
+      { name: 'cup_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, CupRow),
+  __table({
+    name: 'cup_entry',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'cup_id', algorithm: 'btree', columns: [
+        'cupId',
+      ] },
+    ],
+    constraints: [
+      { name: 'cup_entry_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, CupEntryRow),
+  __table({
+    name: 'cup_tie',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'cup_id', algorithm: 'btree', columns: [
+        'cupId',
+      ] },
+      { name: 'match_id', algorithm: 'btree', columns: [
+        'matchId',
+      ] },
+    ],
+    constraints: [
+      { name: 'cup_tie_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, CupTieRow),
   __table({
     name: 'event',
     indexes: [
       { name: 'id', algorithm: 'btree', columns: [
         'id',
       ] },
+      { name: 'topic_id', algorithm: 'btree', columns: [
+        'topicId',
+      ] },
     ],
     constraints: [
       { name: 'event_id_key', constraint: 'unique', columns: ['id'] },
     ],
   }, EventRow),
+  __table({
+    name: 'fixture',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'season_id', algorithm: 'btree', columns: [
+        'seasonId',
+      ] },
+      { name: 'match_id', algorithm: 'btree', columns: [
+        'matchId',
+      ] },
+    ],
+    constraints: [
+      { name: 'fixture_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, FixtureRow),
+  __table({
+    name: 'fixture_kickoff',
+    indexes: [
+      { name: 'scheduled_id', algorithm: 'btree', columns: [
+        'scheduledId',
+      ] },
+    ],
+    constraints: [
+      { name: 'fixture_kickoff_scheduled_id_key', constraint: 'unique', columns: ['scheduledId'] },
+    ],
+  }, FixtureKickoffRow),
+  __table({
+    name: 'hold_exemption',
+    indexes: [
+      { name: 'fid', algorithm: 'btree', columns: [
+        'fid',
+      ] },
+    ],
+    constraints: [
+      { name: 'hold_exemption_fid_key', constraint: 'unique', columns: ['fid'] },
+    ],
+  }, HoldExemptionRow),
   __table({
     name: 'idempotency',
     indexes: [
@@ -231,12 +542,29 @@ const tablesSchema = __schema(
       { name: 'idempotency_id_key', constraint: 'unique', columns: ['id'] },
     ],
   }, IdempotencyRow),
+  __table({
+    name: 'identity_link',
+    indexes: [
+      { name: 'identity', algorithm: 'btree', columns: [
+        'identity',
+      ] },
+      { name: 'fid', algorithm: 'btree', columns: [
+        'fid',
+      ] },
+    ],
+    constraints: [
+      { name: 'identity_link_identity_key', constraint: 'unique', columns: ['identity'] },
+    ],
+  }, IdentityLinkRow),
   __table({
     name: 'inbox',
     indexes: [
       { name: 'msg_id', algorithm: 'btree', columns: [
         'msgId',
       ] },
+      { name: 'fid', algorithm: 'btree', columns: [
+        'fid',
+      ] },
     ],
     constraints: [
       { name: 'inbox_msg_id_key', constraint: 'unique', columns: ['msgId'] },
@@ -248,22 +576,135 @@ const tablesSchema = __schema(
       { name: 'item_id', algorithm: 'btree', columns: [
         'itemId',
       ] },
+      { name: 'owner_fid', algorithm: 'btree', columns: [
+        'ownerFid',
+      ] },
     ],
     constraints: [
       { name: 'inventory_item_item_id_key', constraint: 'unique', columns: ['itemId'] },
     ],
   }, InventoryItemRow),
+  __table({
+    name: 'item_lock',
+    indexes: [
+      { name: 'item_id', algorithm: 'btree', columns: [
+        'itemId',
+      ] },
+    ],
+    constraints: [
+      { name: 'item_lock_item_id_key', constraint: 'unique', columns: ['itemId'] },
+    ],
+  }, ItemLockRow),
+  __table({
+    name: 'league',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'pyramid', algorithm: 'btree', columns: [
+        'pyramid',
+      ] },
+    ],
+    constraints: [
+      { name: 'league_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, LeagueRow),
+  __table({
+    name: 'league_member',
+    indexes: [
+      { name: 'fid', algorithm: 'btree', columns: [
+        'fid',
+      ] },
+      { name: 'league_id', algorithm: 'btree', columns: [
+        'leagueId',
+      ] },
+    ],
+    constraints: [
+      { name: 'league_member_fid_key', constraint: 'unique', columns: ['fid'] },
+    ],
+  }, LeagueMemberRow),
+  __table({
+    name: 'ledger_entry',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'fid', algorithm: 'btree', columns: [
+        'fid',
+      ] },
+    ],
+    constraints: [
+      { name: 'ledger_entry_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, LedgerEntryRow),
+  __table({
+    name: 'lineup',
+    indexes: [
+      { name: 'fid', algorithm: 'btree', columns: [
+        'fid',
+      ] },
+    ],
+    constraints: [
+      { name: 'lineup_fid_key', constraint: 'unique', columns: ['fid'] },
+    ],
+  }, LineupRow),
+  __table({
+    name: 'lineup_slot',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'item_id', algorithm: 'btree', columns: [
+        'itemId',
+      ] },
+      { name: 'fid_slot', algorithm: 'btree', columns: [
+        'fid',
+        'slot',
+      ] },
+    ],
+    constraints: [
+      { name: 'lineup_slot_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, LineupSlotRow),
   __table({
     name: 'listing',
     indexes: [
       { name: 'id', algorithm: 'btree', columns: [
         'id',
       ] },
+      { name: 'item_id', algorithm: 'btree', columns: [
+        'itemId',
+      ] },
     ],
     constraints: [
       { name: 'listing_id_key', constraint: 'unique', columns: ['id'] },
     ],
   }, ListingRow),
+  __table({
+    name: 'listing_reservation',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'listing_id', algorithm: 'btree', columns: [
+        'listingId',
+      ] },
+    ],
+    constraints: [
+      { name: 'listing_reservation_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, ListingReservationRow),
+  __table({
+    name: 'market_limits',
+    indexes: [
+      { name: 'item_type', algorithm: 'btree', columns: [
+        'itemType',
+      ] },
+    ],
+    constraints: [
+      { name: 'market_limits_item_type_key', constraint: 'unique', columns: ['itemType'] },
+    ],
+  }, MarketLimitsRow),
   __table({
     name: 'match_official_assignment',
     indexes: [
@@ -275,6 +716,28 @@ const tablesSchema = __schema(
       { name: 'match_official_assignment_match_id_key', constraint: 'unique', columns: ['matchId'] },
     ],
   }, MatchOfficialAssignmentRow),
+  __table({
+    name: 'matchmaking_queue',
+    indexes: [
+      { name: 'fid', algorithm: 'btree', columns: [
+        'fid',
+      ] },
+    ],
+    constraints: [
+      { name: 'matchmaking_queue_fid_key', constraint: 'unique', columns: ['fid'] },
+    ],
+  }, MatchmakingQueueRow),
+  __table({
+    name: 'matchmaking_schedule',
+    indexes: [
+      { name: 'scheduled_id', algorithm: 'btree', columns: [
+        'scheduledId',
+      ] },
+    ],
+    constraints: [
+      { name: 'matchmaking_schedule_scheduled_id_key', constraint: 'unique', columns: ['scheduledId'] },
+    ],
+  }, MatchmakingScheduleRow),
   __table({
     name: 'npc_assignment',
     indexes: [
@@ -292,6 +755,12 @@ const tablesSchema = __schema(
       { name: 'npc_fid', algorithm: 'btree', columns: [
         'npcFid',
       ] },
+      { name: 'token_id', algorithm: 'btree', columns: [
+        'tokenId',
+      ] },
+      { name: 'difficulty_tier', algorithm: 'btree', columns: [
+        'difficultyTier',
+      ] },
     ],
     constraints: [
       { name: 'npc_registry_npc_fid_key', constraint: 'unique', columns: ['npcFid'] },
@@ -308,6 +777,67 @@ const tablesSchema = __schema(
       { name: 'officials_official_id_key', constraint: 'unique', columns: ['officialId'] },
     ],
   }, OfficialsRow),
+  __table({
+    name: 'operator_role',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'identity', algorithm: 'btree', columns: [
+        'identity',
+      ] },
+    ],
+    constraints: [
+      { name: 'operator_role_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, OperatorRoleRow),
+  __table({
+    name: 'player_age_schedule',
+    indexes: [
+      { name: 'scheduled_id', algorithm: 'btree', columns: [
+        'scheduledId',
+      ] },
+    ],
+    constraints: [
+      { name: 'player_age_schedule_scheduled_id_key', constraint: 'unique', columns: ['scheduledId'] },
+    ],
+  }, PlayerAgeScheduleRow),
+  __table({
+    name: 'player_card',
+    indexes: [
+      { name: 'item_id', algorithm: 'btree', columns: [
+        'itemId',
+      ] },
+    ],
+    constraints: [
+      { name: 'player_card_item_id_key', constraint: 'unique', columns: ['itemId'] },
+    ],
+  }, PlayerCardRow),
+  __table({
+    name: 'player_match_log',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'ts_ms', algorithm: 'btree', columns: [
+        'tsMs',
+      ] },
+    ],
+    constraints: [
+      { name: 'player_match_log_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, PlayerMatchLogRow),
+  __table({
+    name: 'player_recover_schedule',
+    indexes: [
+      { name: 'scheduled_id', algorithm: 'btree', columns: [
+        'scheduledId',
+      ] },
+    ],
+    constraints: [
+      { name: 'player_recover_schedule_scheduled_id_key', constraint: 'unique', columns: ['scheduledId'] },
+    ],
+  }, PlayerRecoverScheduleRow),
   __table({
     name: 'player_state',
     indexes: [
@@ -319,28 +849,139 @@ const tablesSchema = __schema(
       { name: 'player_state_player_id_key', constraint: 'unique', columns: ['playerId'] },
     ],
   }, PlayerStateRow),
+  __table({
+    name: 'proxy_bid',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'auction_id', algorithm: 'btree', columns: [
+        'auctionId',
+      ] },
+    ],
+    constraints: [
+      { name: 'proxy_bid_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, ProxyBidRow),
+  __table({
+    name: 'pvp_config',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+    ],
+    constraints: [
+      { name: 'pvp_config_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, PvpConfigRow),
+  __table({
+    name: 'pvp_confirm_timer',
+    indexes: [
+      { name: 'scheduled_id', algorithm: 'btree', columns: [
+        'scheduledId',
+      ] },
+    ],
+    constraints: [
+      { name: 'pvp_confirm_timer_scheduled_id_key', constraint: 'unique', columns: ['scheduledId'] },
+    ],
+  }, PvpConfirmTimerRow),
+  __table({
+    name: 'pvp_dispute',
+    indexes: [
+      { name: 'match_id', algorithm: 'btree', columns: [
+        'matchId',
+      ] },
+    ],
+    constraints: [
+      { name: 'pvp_dispute_match_id_key', constraint: 'unique', columns: ['matchId'] },
+    ],
+  }, PvpDisputeRow),
   __table({
     name: 'pvp_match',
     indexes: [
       { name: 'id', algorithm: 'btree', columns: [
         'id',
       ] },
+      { name: 'challenged_fid', algorithm: 'btree', columns: [
+        'challengedFid',
+      ] },
+      { name: 'pair_status', algorithm: 'btree', columns: [
+        'challengerFid',
+        'challengedFid',
+        'status',
+      ] },
     ],
     constraints: [
       { name: 'pvp_match_id_key', constraint: 'unique', columns: ['id'] },
     ],
   }, PvpMatchRow),
+  __table({
+    name: 'pvp_match_timer',
+    indexes: [
+      { name: 'scheduled_id', algorithm: 'btree', columns: [
+        'scheduledId',
+      ] },
+    ],
+    constraints: [
+      { name: 'pvp_match_timer_scheduled_id_key', constraint: 'unique', columns: ['scheduledId'] },
+    ],
+  }, PvpMatchTimerRow),
+  __table({
+    name: 'rating_history',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'fid', algorithm: 'btree', columns: [
+        'fid',
+      ] },
+    ],
+    constraints: [
+      { name: 'rating_history_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, RatingHistoryRow),
+  __table({
+    name: 'season',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'pyramid', algorithm: 'btree', columns: [
+        'pyramid',
+      ] },
+    ],
+    constraints: [
+      { name: 'season_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, SeasonRow),
   __table({
     name: 'squad_registry',
     indexes: [
       { name: 'squad_id', algorithm: 'btree', columns: [
         'squadId',
       ] },
+      { name: 'token_id', algorithm: 'btree', columns: [
+        'tokenId',
+      ] },
     ],
     constraints: [
       { name: 'squad_registry_squad_id_key', constraint: 'unique', columns: ['squadId'] },
     ],
   }, SquadRegistryRow),
+  __table({
+    name: 'standing',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'season_id', algorithm: 'btree', columns: [
+        'seasonId',
+      ] },
+    ],
+    constraints: [
+      { name: 'standing_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, StandingRow),
   __table({
     name: 'starter_claim',
     indexes: [
@@ -390,17 +1031,39 @@ const tablesSchema = __schema(
 /** The schema information for all reducers in this module. This is defined the same way as the reducers would have been defined in the server, except the body of the reducer is omitted in code generation. */
 const reducersSchema = __reducers(
   __reducerSchema("auction_buy_now_apply", AuctionBuyNowApply),
+  __reducerSchema("auction_close_tick", AuctionCloseTick),
+  __reducerSchema("balance_deposit", BalanceDeposit),
+  __reducerSchema("balance_withdraw", BalanceWithdraw),
   __reducerSchema("buy_now", BuyNow),
+  __reducerSchema("cancel_auction", CancelAuction),
+  __reducerSchema("cancel_listing", CancelListing),
   __reducerSchema("close_listing_and_transfer", CloseListingAndTransfer),
   __reducerSchema("commentary_append", CommentaryAppend),
   __reducerSchema("create_auction", CreateAuction),
   __reducerSchema("create_listing", CreateListing),
+  __reducerSchema("cup_cancel", CupCancel),
+  __reducerSchema("cup_create", CupCreate),
+  __reducerSchema("cup_enter", CupEnter),
+  __reducerSchema("cup_start", CupStart),
   __reducerSchema("finalize_auction", FinalizeAuction),
+  __reducerSchema("fixture_kickoff_tick", FixtureKickoffTick),
   __reducerSchema("grant_starter_pack", GrantStarterPack),
+  __reducerSchema("hold_exemption_set", HoldExemptionSet),
+  __reducerSchema("identity_sign_in", IdentitySignIn),
+  __reducerSchema("identity_unlink", IdentityUnlink),
   __reducerSchema("inbox_mark_read", InboxMarkRead),
+  __reducerSchema("league_create", LeagueCreate),
+  __reducerSchema("lineup_assign", LineupAssign),
+  __reducerSchema("lineup_set_captain", LineupSetCaptain),
+  __reducerSchema("lineup_set_formation", LineupSetFormation),
   __reducerSchema("link_wallet", LinkWallet),
+  __reducerSchema("listing_reserve_purchase", ListingReservePurchase),
   __reducerSchema("mark_tx_used", MarkTxUsed),
+  __reducerSchema("market_limits_set", MarketLimitsSet),
   __reducerSchema("marketplace_purchase_apply", MarketplacePurchaseApply),
+  __reducerSchema("matchmaking_enqueue", MatchmakingEnqueue),
+  __reducerSchema("matchmaking_leave", MatchmakingLeave),
+  __reducerSchema("matchmaking_tick", MatchmakingTick),
   __reducerSchema("npc_assign_for_user", NpcAssignForUser),
   __reducerSchema("npc_create", NpcCreate),
   __reducerSchema("npc_mint_token", NpcMintToken),
@@ -409,15 +1072,30 @@ const reducersSchema = __reducers(
   __reducerSchema("official_create", OfficialCreate),
   __reducerSchema("official_set_active", OfficialSetActive),
   __reducerSchema("official_update_after_match", OfficialUpdateAfterMatch),
+  __reducerSchema("operator_grant", OperatorGrant),
+  __reducerSchema("operator_revoke", OperatorRevoke),
   __reducerSchema("place_bid", PlaceBid),
   __reducerSchema("player_age_tick", PlayerAgeTick),
   __reducerSchema("player_profile_init", PlayerProfileInit),
   __reducerSchema("player_state_apply_match", PlayerStateApplyMatch),
   __reducerSchema("player_state_recover_tick", PlayerStateRecoverTick),
+  __reducerSchema("player_ticks_schedule", PlayerTicksSchedule),
   __reducerSchema("pvp_accept", PvpAccept),
+  __reducerSchema("pvp_cancel_challenge", PvpCancelChallenge),
+  __reducerSchema("pvp_config_set", PvpConfigSet),
+  __reducerSchema("pvp_confirm_result", PvpConfirmResult),
+  __reducerSchema("pvp_confirm_timeout_tick", PvpConfirmTimeoutTick),
   __reducerSchema("pvp_create_challenge", PvpCreateChallenge),
+  __reducerSchema("pvp_decline_challenge", PvpDeclineChallenge),
+  __reducerSchema("pvp_dispute_result", PvpDisputeResult),
+  __reducerSchema("pvp_match_timeout_tick", PvpMatchTimeoutTick),
+  __reducerSchema("pvp_resolve_dispute", PvpResolveDispute),
+  __reducerSchema("pvp_simulate_match", PvpSimulateMatch),
   __reducerSchema("pvp_submit_result", PvpSubmitResult),
+  __reducerSchema("season_start", SeasonStart),
+  __reducerSchema("set_max_bid", SetMaxBid),
   __reducerSchema("squad_mint_from_farcaster", SquadMintFromFarcaster),
+  __reducerSchema("update_listing_price", UpdateListingPrice),
   __reducerSchema("var_review_record", VarReviewRecord),
 );
 
@@ -14,8 +14,10 @@ export default __t.row({
   id: __t.string().primaryKey(),
   itemId: __t.string(),
   sellerFid: __t.i64(),
-  priceWei: __t.string(),
+  priceWei: __t.u256(),
   status: __t.string(),
   createdAtMs: __t.i64(),
   closedAtMs: __t.option(__t.i64()),
+  pendingBuyerFid: __t.option(__t.i64()),
+  pendingUntilMs: __t.option(__t.i64()),
 });
@@ -14,10 +14,12 @@ export default __t.object("Listing", {
   id: __t.string(),
   itemId: __t.string(),
   sellerFid: __t.i64(),
-  priceWei: __t.string(),
+  priceWei: __t.u256(),
   status: __t.string(),
   createdAtMs: __t.i64(),
   closedAtMs: __t.option(__t.i64()),
+  pendingBuyerFid: __t.option(__t.i64()),
+  pendingUntilMs: __t.option(__t.i64()),
 });
 
 
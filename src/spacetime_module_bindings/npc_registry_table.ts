@@ -12,10 +12,10 @@ import {
 
 export default __t.row({
   npcFid: __t.i64().primaryKey(),
-  tokenId: __t.option(__t.string()),
+  tokenId: __t.string(),
   aiSeed: __t.i64(),
   difficultyTier: __t.i16(),
-  budgetFbcWei: __t.string(),
+  budgetFbcWei: __t.u256(),
   persona: __t.string(),
   ownerFid: __t.option(__t.i64()),
   managerConfidence: __t.i32(),
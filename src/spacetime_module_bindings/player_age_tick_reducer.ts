@@ -9,5 +9,8 @@ import {
   type AlgebraicTypeType as __AlgebraicTypeType,
   type Infer as __Infer,
 } from "spacetimedb";
+import PlayerAgeSchedule from "./player_age_schedule_type";
 
-export default {};
+export default {
+  schedule: PlayerAgeSchedule,
+};
@@ -9,7 +9,8 @@ import {
   type AlgebraicTypeType as __AlgebraicTypeType,
   type Infer as __Infer,
 } from "spacetimedb";
+import PlayerRecoverSchedule from "./player_recover_schedule_type";
 
 export default {
-  nowMs: __t.i64(),
+  schedule: PlayerRecoverSchedule,
 };
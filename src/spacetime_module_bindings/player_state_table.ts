@@ -23,4 +23,5 @@ export default __t.row({
   matchesPlayed7D: __t.i32(),
   matchesBenched7D: __t.i32(),
   lastMatchAtMs: __t.option(__t.i64()),
+  ageDecline: __t.i32(),
 });
@@ -23,6 +23,7 @@ export default __t.object("PlayerState", {
   matchesPlayed7D: __t.i32(),
   matchesBenched7D: __t.i32(),
   lastMatchAtMs: __t.option(__t.i64()),
+  ageDecline: __t.i32(),
 });
 
 
@@ -18,4 +18,7 @@ export default __t.row({
   createdAtMs: __t.i64(),
   acceptedAtMs: __t.option(__t.i64()),
   resultJson: __t.option(__t.string()),
+  reportedByFid: __t.option(__t.i64()),
+  reportedAtMs: __t.option(__t.i64()),
+  finalizedAtMs: __t.option(__t.i64()),
 });
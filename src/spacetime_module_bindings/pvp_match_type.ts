@@ -18,6 +18,9 @@ export default __t.object("PvpMatch", {
   createdAtMs: __t.i64(),
   acceptedAtMs: __t.option(__t.i64()),
   resultJson: __t.option(__t.string()),
+  reportedByFid: __t.option(__t.i64()),
+  reportedAtMs: __t.option(__t.i64()),
+  finalizedAtMs: __t.option(__t.i64()),
 });
 
 
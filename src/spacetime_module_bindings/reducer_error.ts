@@ -0,0 +1,95 @@
+// THIS FILE IS AUTOMATICALLY GENERATED BY scripts/gen-reducer-errors.mjs FROM
+// spacetime-server/rust/footballcaster2/src/error.rs. EDIT THE RUST SOURCE INSTEAD.
+
+/* eslint-disable */
+/* tslint:disable */
+export const ReducerErrorCode = {
+  IdentityNotLinked: 'identity_not_linked',
+  FidMismatch: 'fid_mismatch',
+  Forbidden: 'forbidden',
+  InvalidRole: 'invalid_role',
+  LastAdmin: 'last_admin',
+  SchedulerOnly: 'scheduler_only',
+  ItemNotFound: 'item_not_found',
+  NotOwner: 'not_owner',
+  InHold: 'in_hold',
+  ItemLocked: 'item_locked',
+  InvalidPayload: 'invalid_payload',
+  StarterAlreadyClaimed: 'starter_already_claimed',
+  ListingNotFound: 'listing_not_found',
+  ListingClosed: 'listing_closed',
+  PurchasePending: 'purchase_pending',
+  ReservationUsed: 'reservation_used',
+  PriceBelowMinimum: 'price_below_minimum',
+  InvalidMarketLimits: 'invalid_market_limits',
+  AuctionNotFound: 'auction_not_found',
+  AuctionClosed: 'auction_closed',
+  AuctionEnded: 'auction_ended',
+  AuctionNotEnded: 'auction_not_ended',
+  NotWinner: 'not_winner',
+  BelowIncrement: 'below_increment',
+  InvalidBuyNow: 'invalid_buy_now',
+  InvalidAntiSnipe: 'invalid_anti_snipe',
+  AuctionTooShort: 'auction_too_short',
+  AuctionTooLong: 'auction_too_long',
+  InvalidReserve: 'invalid_reserve',
+  BuyNowBelowReserve: 'buy_now_below_reserve',
+  InvalidAmount: 'invalid_amount',
+  AmountOverflow: 'amount_overflow',
+  InsufficientFunds: 'insufficient_funds',
+  EscrowUnderflow: 'escrow_underflow',
+  TxAlreadyUsed: 'tx_already_used',
+  SameFid: 'same_fid',
+  DuplicatePending: 'duplicate_pending',
+  MatchNotFound: 'match_not_found',
+  InvalidState: 'invalid_state',
+  NotChallenged: 'not_challenged',
+  NotChallenger: 'not_challenger',
+  NotParticipant: 'not_participant',
+  InvalidJson: 'invalid_json',
+  MissingHome: 'missing_home',
+  MissingAway: 'missing_away',
+  NegativeScore: 'negative_score',
+  ScoreOutOfRange: 'score_out_of_range',
+  AlreadyReported: 'already_reported',
+  DisputeNotFound: 'dispute_not_found',
+  AlreadyQueued: 'already_queued',
+  NotQueued: 'not_queued',
+  NpcMatch: 'npc_match',
+  InsufficientNpcPool: 'insufficient_npc_pool',
+  NotAPlayer: 'not_a_player',
+  PlayerStateExists: 'player_state_exists',
+  InvalidResult: 'invalid_result',
+  OutOfRange: 'out_of_range',
+  InvalidFormation: 'invalid_formation',
+  InvalidSlot: 'invalid_slot',
+  PositionMismatch: 'position_mismatch',
+  PlayerInjured: 'player_injured',
+  NotInLineup: 'not_in_lineup',
+  LeagueNotFound: 'league_not_found',
+  LeagueExists: 'league_exists',
+  AlreadyInLeague: 'already_in_league',
+  SeasonActive: 'season_active',
+  PromotionMismatch: 'promotion_mismatch',
+  CupNotFound: 'cup_not_found',
+  CupClosed: 'cup_closed',
+  CupFull: 'cup_full',
+  AlreadyEntered: 'already_entered',
+  NotEnoughEntrants: 'not_enough_entrants',
+  InvalidPrizeSplit: 'invalid_prize_split',
+} as const;
+
+export type ReducerErrorCode = (typeof ReducerErrorCode)[keyof typeof ReducerErrorCode];
+
+const CODES: ReadonlySet<string> = new Set(Object.values(ReducerErrorCode));
+
+/** Extracts the module error code from a failed reducer call, if it carries one. */
+export function parseReducerError(err: unknown): ReducerErrorCode | null {
+  const message = typeof err === 'string' ? err : (err as any)?.message ?? String(err ?? '');
+  const trimmed = String(message).trim();
+  if (CODES.has(trimmed)) return trimmed as ReducerErrorCode;
+  for (const token of trimmed.match(/[a-z0-9_]+/g) ?? []) {
+    if (CODES.has(token)) return token as ReducerErrorCode;
+  }
+  return null;
+}